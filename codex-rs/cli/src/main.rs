@@ -80,7 +80,7 @@ enum Subcommand {
     #[clap(visible_alias = "a")]
     Apply(ApplyCommand),
 
-    /// Resume a previous interactive session (picker by default; use --last to continue the most recent).
+    /// Resume a previous interactive session (picker by default; use --last to continue the most recent, or --branch for the most recent on a git branch).
     Resume(ResumeCommand),
 
     /// Internal: generate TypeScript protocol bindings.
@@ -106,6 +106,20 @@ struct ResumeCommand {
     #[arg(long = "last", default_value_t = false, conflicts_with = "session_id")]
     last: bool,
 
+    /// Continue the most recent session started in the current directory
+    /// while the given git branch was checked out.
+    #[arg(
+        long = "branch",
+        value_name = "BRANCH",
+        conflicts_with_all = ["session_id", "last"]
+    )]
+    branch: Option<String>,
+
+    /// Browse and resume sessions recorded under another Codex home (for
+    /// example a separate work `CODEX_HOME`) instead of the active one.
+    #[arg(long = "sessions-from", value_name = "CODEX_HOME")]
+    sessions_from: Option<PathBuf>,
+
     #[clap(flatten)]
     config_overrides: TuiCli,
 }
@@ -261,6 +275,8 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
         Some(Subcommand::Resume(ResumeCommand {
             session_id,
             last,
+            branch,
+            sessions_from,
             config_overrides,
         })) => {
             interactive = finalize_resume_interactive(
@@ -268,6 +284,8 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
                 root_config_overrides.clone(),
                 session_id,
                 last,
+                branch,
+                sessions_from,
                 config_overrides,
             );
             codex_tui::run_main(interactive, codex_linux_sandbox_exe).await?;
@@ -363,14 +381,18 @@ fn finalize_resume_interactive(
     root_config_overrides: CliConfigOverrides,
     session_id: Option<String>,
     last: bool,
+    branch: Option<String>,
+    sessions_from: Option<PathBuf>,
     resume_cli: TuiCli,
 ) -> TuiCli {
     // Start with the parsed interactive CLI so resume shares the same
     // configuration surface area as `codex` without additional flags.
     let resume_session_id = session_id;
-    interactive.resume_picker = resume_session_id.is_none() && !last;
+    interactive.resume_picker = resume_session_id.is_none() && !last && branch.is_none();
     interactive.resume_last = last;
     interactive.resume_session_id = resume_session_id;
+    interactive.resume_branch = branch;
+    interactive.resume_sessions_home = sessions_from;
 
     // Merge resume-scoped flags and overrides with highest precedence.
     merge_resume_cli_flags(&mut interactive, resume_cli);
@@ -391,6 +413,9 @@ fn merge_resume_cli_flags(interactive: &mut TuiCli, resume_cli: TuiCli) {
     if resume_cli.oss {
         interactive.oss = true;
     }
+    if let Some(provider) = resume_cli.model_provider {
+        interactive.model_provider = Some(provider);
+    }
     if let Some(profile) = resume_cli.config_profile {
         interactive.config_profile = Some(profile);
     }
@@ -448,13 +473,23 @@ mod tests {
         let Subcommand::Resume(ResumeCommand {
             session_id,
             last,
+            branch,
+            sessions_from,
             config_overrides: resume_cli,
         }) = subcommand.expect("resume present")
         else {
             unreachable!()
         };
 
-        finalize_resume_interactive(interactive, root_overrides, session_id, last, resume_cli)
+        finalize_resume_interactive(
+            interactive,
+            root_overrides,
+            session_id,
+            last,
+            branch,
+            sessions_from,
+            resume_cli,
+        )
     }
 
     fn sample_exit_info(conversation: Option<&str>) -> AppExitInfo {
@@ -529,6 +564,33 @@ mod tests {
         assert_eq!(interactive.resume_session_id, None);
     }
 
+    #[test]
+    fn resume_picker_logic_branch() {
+        let interactive = finalize_from_args(["codex", "resume", "--branch", "feature"].as_ref());
+        assert!(!interactive.resume_picker);
+        assert!(!interactive.resume_last);
+        assert_eq!(interactive.resume_session_id, None);
+        assert_eq!(interactive.resume_branch.as_deref(), Some("feature"));
+    }
+
+    #[test]
+    fn resume_branch_conflicts_with_last() {
+        let result =
+            MultitoolCli::try_parse_from(["codex", "resume", "--last", "--branch", "feature"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn resume_sessions_from_keeps_picker() {
+        let interactive =
+            finalize_from_args(["codex", "resume", "--sessions-from", "/tmp/work-home"].as_ref());
+        assert!(interactive.resume_picker);
+        assert_eq!(
+            interactive.resume_sessions_home,
+            Some(PathBuf::from("/tmp/work-home"))
+        );
+    }
+
     #[test]
     fn resume_picker_logic_with_session_id() {
         let interactive = finalize_from_args(["codex", "resume", "1234"].as_ref());
@@ -594,6 +656,21 @@ mod tests {
         assert_eq!(interactive.resume_session_id.as_deref(), Some("sid"));
     }
 
+    #[test]
+    fn resume_merges_provider_flag() {
+        let interactive =
+            finalize_from_args(["codex", "resume", "--last", "--provider", "ollama"].as_ref());
+        assert_eq!(interactive.model_provider.as_deref(), Some("ollama"));
+        assert!(!interactive.oss);
+        assert!(interactive.resume_last);
+    }
+
+    #[test]
+    fn provider_flag_conflicts_with_oss() {
+        let result = MultitoolCli::try_parse_from(["codex", "--oss", "--provider", "ollama"]);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn resume_merges_dangerously_bypass_flag() {
         let interactive = finalize_from_args(
@@ -1,4 +1,5 @@
 use crate::config_profile::ConfigProfile;
+use crate::config_types::FilterCase;
 use crate::config_types::History;
 use crate::config_types::McpServerConfig;
 use crate::config_types::Notifications;
@@ -48,6 +49,9 @@ pub(crate) const PROJECT_DOC_MAX_BYTES: usize = 32 * 1024; // 32 KiB
 
 pub(crate) const CONFIG_TOML_FILE: &str = "config.toml";
 
+/// Default number of days deleted sessions stay in the trash.
+pub(crate) const DEFAULT_SESSION_TRASH_RETENTION_DAYS: u64 = 30;
+
 /// Application configuration loaded from disk and merged with overrides.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Config {
@@ -122,6 +126,13 @@ pub struct Config {
     /// and turn completions when not focused.
     pub tui_notifications: Notifications,
 
+    /// Days that sessions deleted from the TUI resume picker stay in the
+    /// trash before being purged.
+    pub tui_session_trash_retention_days: u64,
+
+    /// Case sensitivity of the TUI resume picker's search text.
+    pub tui_resume_filter_case: FilterCase,
+
     /// The directory that should be treated as the current working directory
     /// for the session. All relative paths inside the business-logic layer are
     /// resolved against this path.
@@ -194,6 +205,57 @@ pub struct Config {
     /// All characters are inserted as they are received, and no buffering
     /// or placeholder replacement will occur for fast keypress bursts.
     pub disable_paste_burst: bool,
+
+    /// When true, resuming a session switches to the model and reasoning
+    /// effort recorded for its latest turn instead of the configured ones.
+    pub resume_restores_model: bool,
+
+    /// Model settings set explicitly in `config.toml`, kept so the model
+    /// family and token limits can be re-derived when the model changes.
+    pub model_overrides: ModelOverrides,
+}
+
+/// Explicit `config.toml` values that take precedence over what is derived
+/// from the model's family and built-in model info.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ModelOverrides {
+    pub supports_reasoning_summaries: Option<bool>,
+    pub reasoning_summary_format: Option<ReasoningSummaryFormat>,
+    pub context_window: Option<u64>,
+    pub max_output_tokens: Option<u64>,
+    pub auto_compact_token_limit: Option<i64>,
+}
+
+impl ModelOverrides {
+    fn model_family(&self, model: &str) -> ModelFamily {
+        let mut model_family =
+            find_family_for_model(model).unwrap_or_else(|| derive_default_model_family(model));
+        if let Some(supports_reasoning_summaries) = self.supports_reasoning_summaries {
+            model_family.supports_reasoning_summaries = supports_reasoning_summaries;
+        }
+        if let Some(reasoning_summary_format) = self.reasoning_summary_format.clone() {
+            model_family.reasoning_summary_format = reasoning_summary_format;
+        }
+        model_family
+    }
+
+    /// Context window, max output tokens and auto-compact limit for
+    /// `model_family`, preferring the explicit overrides.
+    fn token_limits(&self, model_family: &ModelFamily) -> (Option<u64>, Option<u64>, Option<i64>) {
+        let model_info = get_model_info(model_family);
+        let context_window = self
+            .context_window
+            .or_else(|| model_info.as_ref().map(|info| info.context_window));
+        let max_output_tokens = self
+            .max_output_tokens
+            .or_else(|| model_info.as_ref().map(|info| info.max_output_tokens));
+        let auto_compact_token_limit = self.auto_compact_token_limit.or_else(|| {
+            model_info
+                .as_ref()
+                .and_then(|info| info.auto_compact_token_limit)
+        });
+        (context_window, max_output_tokens, auto_compact_token_limit)
+    }
 }
 
 impl Config {
@@ -703,6 +765,10 @@ pub struct ConfigToml {
     /// All characters are inserted as they are received, and no buffering
     /// or placeholder replacement will occur for fast keypress bursts.
     pub disable_paste_burst: Option<bool>,
+
+    /// When true, resuming a session restores the model and reasoning effort
+    /// it last used. Defaults to `false` (keep the current settings).
+    pub resume_restores_model: Option<bool>,
 }
 
 impl From<ConfigToml> for UserSavedConfig {
@@ -949,30 +1015,17 @@ impl Config {
             .or(cfg.model)
             .unwrap_or_else(default_model);
 
-        let mut model_family =
-            find_family_for_model(&model).unwrap_or_else(|| derive_default_model_family(&model));
+        let model_overrides = ModelOverrides {
+            supports_reasoning_summaries: cfg.model_supports_reasoning_summaries,
+            reasoning_summary_format: cfg.model_reasoning_summary_format.clone(),
+            context_window: cfg.model_context_window,
+            max_output_tokens: cfg.model_max_output_tokens,
+            auto_compact_token_limit: cfg.model_auto_compact_token_limit,
+        };
+        let model_family = model_overrides.model_family(&model);
 
-        if let Some(supports_reasoning_summaries) = cfg.model_supports_reasoning_summaries {
-            model_family.supports_reasoning_summaries = supports_reasoning_summaries;
-        }
-        if let Some(model_reasoning_summary_format) = cfg.model_reasoning_summary_format {
-            model_family.reasoning_summary_format = model_reasoning_summary_format;
-        }
-
-        let openai_model_info = get_model_info(&model_family);
-        let model_context_window = cfg
-            .model_context_window
-            .or_else(|| openai_model_info.as_ref().map(|info| info.context_window));
-        let model_max_output_tokens = cfg.model_max_output_tokens.or_else(|| {
-            openai_model_info
-                .as_ref()
-                .map(|info| info.max_output_tokens)
-        });
-        let model_auto_compact_token_limit = cfg.model_auto_compact_token_limit.or_else(|| {
-            openai_model_info
-                .as_ref()
-                .and_then(|info| info.auto_compact_token_limit)
-        });
+        let (model_context_window, model_max_output_tokens, model_auto_compact_token_limit) =
+            model_overrides.token_limits(&model_family);
 
         // Load base instructions override from a file if specified. If the
         // path is relative, resolve it against the effective cwd so the
@@ -1046,15 +1099,42 @@ impl Config {
             include_view_image_tool,
             active_profile: active_profile_name,
             disable_paste_burst: cfg.disable_paste_burst.unwrap_or(false),
+            resume_restores_model: cfg.resume_restores_model.unwrap_or(false),
+            model_overrides,
             tui_notifications: cfg
                 .tui
                 .as_ref()
                 .map(|t| t.notifications.clone())
                 .unwrap_or_default(),
+            tui_session_trash_retention_days: cfg
+                .tui
+                .as_ref()
+                .and_then(|t| t.session_trash_retention_days)
+                .unwrap_or(DEFAULT_SESSION_TRASH_RETENTION_DAYS),
+            tui_resume_filter_case: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.resume_filter_case)
+                .unwrap_or_default(),
         };
         Ok(config)
     }
 
+    /// Switch to a model and reasoning effort recorded by an earlier session,
+    /// re-deriving the model family and token limits when the model changes.
+    pub fn restore_recorded_model(&mut self, model: &str, effort: Option<ReasoningEffort>) {
+        if self.model != model {
+            self.model = model.to_string();
+            self.model_family = self.model_overrides.model_family(model);
+            (
+                self.model_context_window,
+                self.model_max_output_tokens,
+                self.model_auto_compact_token_limit,
+            ) = self.model_overrides.token_limits(&self.model_family);
+        }
+        self.model_reasoning_effort = effort;
+    }
+
     fn load_instructions(codex_dir: Option<&Path>) -> Option<String> {
         let mut p = match codex_dir {
             Some(p) => p.to_path_buf(),
@@ -1654,7 +1734,11 @@ model_verbosity = "high"
                 include_view_image_tool: true,
                 active_profile: Some("o3".to_string()),
                 disable_paste_burst: false,
+                resume_restores_model: false,
+                model_overrides: ModelOverrides::default(),
                 tui_notifications: Default::default(),
+                tui_session_trash_retention_days: DEFAULT_SESSION_TRASH_RETENTION_DAYS,
+                tui_resume_filter_case: FilterCase::Smart,
             },
             o3_profile_config
         );
@@ -1712,7 +1796,11 @@ model_verbosity = "high"
             include_view_image_tool: true,
             active_profile: Some("gpt3".to_string()),
             disable_paste_burst: false,
+            resume_restores_model: false,
+            model_overrides: ModelOverrides::default(),
             tui_notifications: Default::default(),
+            tui_session_trash_retention_days: DEFAULT_SESSION_TRASH_RETENTION_DAYS,
+            tui_resume_filter_case: FilterCase::Smart,
         };
 
         assert_eq!(expected_gpt3_profile_config, gpt3_profile_config);
@@ -1785,7 +1873,11 @@ model_verbosity = "high"
             include_view_image_tool: true,
             active_profile: Some("zdr".to_string()),
             disable_paste_burst: false,
+            resume_restores_model: false,
+            model_overrides: ModelOverrides::default(),
             tui_notifications: Default::default(),
+            tui_session_trash_retention_days: DEFAULT_SESSION_TRASH_RETENTION_DAYS,
+            tui_resume_filter_case: FilterCase::Smart,
         };
 
         assert_eq!(expected_zdr_profile_config, zdr_profile_config);
@@ -1844,7 +1936,11 @@ model_verbosity = "high"
             include_view_image_tool: true,
             active_profile: Some("gpt5".to_string()),
             disable_paste_burst: false,
+            resume_restores_model: false,
+            model_overrides: ModelOverrides::default(),
             tui_notifications: Default::default(),
+            tui_session_trash_retention_days: DEFAULT_SESSION_TRASH_RETENTION_DAYS,
+            tui_resume_filter_case: FilterCase::Smart,
         };
 
         assert_eq!(expected_gpt5_profile_config, gpt5_profile_config);
@@ -1852,6 +1948,50 @@ model_verbosity = "high"
         Ok(())
     }
 
+    #[test]
+    fn model_provider_override_selects_configured_provider() -> std::io::Result<()> {
+        let fixture = create_test_fixture()?;
+
+        let overrides = ConfigOverrides {
+            model_provider: Some("openai-chat-completions".to_string()),
+            cwd: Some(fixture.cwd()),
+            ..Default::default()
+        };
+        let config = Config::load_from_base_config_with_overrides(
+            fixture.cfg.clone(),
+            overrides,
+            fixture.codex_home(),
+        )?;
+
+        assert_eq!(config.model_provider_id, "openai-chat-completions");
+        assert_eq!(
+            config.model_provider,
+            fixture.openai_chat_completions_provider
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn model_provider_override_rejects_unknown_provider() -> std::io::Result<()> {
+        let fixture = create_test_fixture()?;
+
+        let overrides = ConfigOverrides {
+            model_provider: Some("does-not-exist".to_string()),
+            cwd: Some(fixture.cwd()),
+            ..Default::default()
+        };
+        let err = Config::load_from_base_config_with_overrides(
+            fixture.cfg.clone(),
+            overrides,
+            fixture.codex_home(),
+        )
+        .expect_err("unknown provider should fail");
+
+        assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+        assert_eq!(err.to_string(), "Model provider `does-not-exist` not found");
+        Ok(())
+    }
+
     #[test]
     fn test_set_project_trusted_writes_explicit_tables() -> anyhow::Result<()> {
         let project_dir = Path::new("/some/path");
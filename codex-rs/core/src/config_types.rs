@@ -175,6 +175,28 @@ pub struct Tui {
     /// Defaults to `false`.
     #[serde(default)]
     pub notifications: Notifications,
+
+    /// Days that sessions deleted from the resume picker are kept in
+    /// `sessions/.trash` before being purged. Defaults to 30.
+    #[serde(default)]
+    pub session_trash_retention_days: Option<u64>,
+
+    /// Case sensitivity of the resume picker's search text. Defaults to
+    /// `smart`.
+    #[serde(default)]
+    pub resume_filter_case: FilterCase,
+}
+
+#[derive(Deserialize, Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum FilterCase {
+    /// Case-insensitive unless the query contains an uppercase letter.
+    #[default]
+    Smart,
+    /// Always match case exactly.
+    Sensitive,
+    /// Always ignore case.
+    Insensitive,
 }
 
 #[derive(Deserialize, Debug, Clone, PartialEq, Default)]
@@ -118,6 +118,12 @@ impl ConversationManager {
         auth_manager: Arc<AuthManager>,
     ) -> CodexResult<NewConversation> {
         let initial_history = RolloutRecorder::get_rollout_history(&rollout_path).await?;
+        let mut config = config;
+        if config.resume_restores_model
+            && let Some(turn) = initial_history.last_turn_context()
+        {
+            config.restore_recorded_model(&turn.model, turn.effort);
+        }
         let CodexSpawnOk {
             codex,
             conversation_id,
@@ -159,6 +165,36 @@ impl ConversationManager {
 
         self.finalize_spawn(codex, conversation_id).await
     }
+
+    /// Start a new conversation seeded with the entire history of the rollout
+    /// at `path`. Unlike resuming, the new conversation has a fresh id and
+    /// records to its own rollout file, leaving the original untouched.
+    pub async fn fork_conversation_from_rollout(
+        &self,
+        config: Config,
+        path: PathBuf,
+    ) -> CodexResult<NewConversation> {
+        let history = RolloutRecorder::get_rollout_history(&path).await?;
+        let history = into_forked_history(history);
+
+        let auth_manager = self.auth_manager.clone();
+        let CodexSpawnOk {
+            codex,
+            conversation_id,
+        } = Codex::spawn(config, auth_manager, history).await?;
+
+        self.finalize_spawn(codex, conversation_id).await
+    }
+}
+
+/// Convert a resumed history into a fork that keeps every rollout item but
+/// drops the link to the original conversation id and rollout path.
+fn into_forked_history(history: InitialHistory) -> InitialHistory {
+    match history {
+        InitialHistory::Resumed(resumed) if resumed.history.is_empty() => InitialHistory::New,
+        InitialHistory::Resumed(resumed) => InitialHistory::Forked(resumed.history),
+        other => other,
+    }
 }
 
 /// Return a prefix of `items` obtained by cutting strictly before the nth user message
@@ -274,6 +310,27 @@ mod tests {
         assert!(matches!(truncated2, InitialHistory::New));
     }
 
+    #[test]
+    fn forked_history_keeps_all_items_without_original_id() {
+        let items: Vec<RolloutItem> = vec![
+            RolloutItem::ResponseItem(user_msg("u1")),
+            RolloutItem::ResponseItem(assistant_msg("a1")),
+        ];
+        let resumed = InitialHistory::Resumed(codex_protocol::protocol::ResumedHistory {
+            conversation_id: ConversationId::new(),
+            history: items.clone(),
+            rollout_path: PathBuf::from("/tmp/rollout.jsonl"),
+        });
+
+        let InitialHistory::Forked(forked) = into_forked_history(resumed) else {
+            panic!("expected a forked history");
+        };
+        assert_eq!(
+            serde_json::to_value(&forked).unwrap(),
+            serde_json::to_value(&items).unwrap()
+        );
+    }
+
     #[test]
     fn ignores_session_prefix_messages_when_truncating() {
         let (session, turn_context) = make_session_and_context();
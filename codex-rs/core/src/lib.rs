@@ -70,8 +70,10 @@ pub mod turn_diff_tracker;
 pub use rollout::ARCHIVED_SESSIONS_SUBDIR;
 pub use rollout::RolloutRecorder;
 pub use rollout::SESSIONS_SUBDIR;
+pub use rollout::SESSIONS_TRASH_SUBDIR;
 pub use rollout::SessionMeta;
 pub use rollout::find_conversation_path_by_id_str;
+pub use rollout::find_latest_conversation_path_for_branch;
 pub use rollout::list::ConversationItem;
 pub use rollout::list::ConversationsPage;
 pub use rollout::list::Cursor;
@@ -7,6 +7,8 @@ use codex_file_search as file_search;
 use std::num::NonZero;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+use std::time::Instant;
 use time::OffsetDateTime;
 use time::PrimitiveDateTime;
 use time::format_description::FormatItem;
@@ -14,9 +16,11 @@ use time::macros::format_description;
 use uuid::Uuid;
 
 use super::SESSIONS_SUBDIR;
+use super::SESSIONS_TRASH_SUBDIR;
 use crate::protocol::EventMsg;
 use codex_protocol::protocol::RolloutItem;
 use codex_protocol::protocol::RolloutLine;
+use codex_protocol::protocol::SessionMetaLine;
 
 /// Returned page of conversation summaries.
 #[derive(Debug, Default, PartialEq)]
@@ -43,6 +47,12 @@ pub struct ConversationItem {
 /// Hard cap to bound worst‑case work per request.
 const MAX_SCAN_FILES: usize = 100;
 const HEAD_RECORD_LIMIT: usize = 10;
+/// Longest JSONL record read while building a head. Larger records (e.g. an
+/// inline base64 image) are skipped without being buffered.
+const MAX_HEAD_LINE_BYTES: usize = 256 * 1024;
+/// Head reads slower than this are logged as warnings so a pathological
+/// rollout that slows down listing can be identified.
+const SLOW_HEAD_READ: Duration = Duration::from_millis(250);
 
 /// Pagination cursor identifying a file by timestamp and UUID.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -148,18 +158,8 @@ async fn traverse_directories_for_paths(
                 if scanned_files >= MAX_SCAN_FILES {
                     break 'outer;
                 }
-                let mut day_files = collect_files(day_path, |name_str, path| {
-                    if !name_str.starts_with("rollout-") || !name_str.ends_with(".jsonl") {
-                        return None;
-                    }
-
-                    parse_timestamp_uuid_from_filename(name_str)
-                        .map(|(ts, id)| (ts, id, name_str.to_string(), path.to_path_buf()))
-                })
-                .await?;
-                // Stable ordering within the same second: (timestamp desc, uuid desc)
-                day_files.sort_by_key(|(ts, sid, _name_str, _path)| (Reverse(*ts), Reverse(*sid)));
-                for (ts, sid, _name_str, path) in day_files.into_iter() {
+                let day_files = collect_rollout_files_desc(day_path).await?;
+                for (ts, sid, path) in day_files.into_iter() {
                     scanned_files += 1;
                     if scanned_files >= MAX_SCAN_FILES && items.len() >= page_size {
                         break 'outer;
@@ -176,10 +176,12 @@ async fn traverse_directories_for_paths(
                     }
                     // Read head and simultaneously detect message events within the same
                     // first N JSONL records to avoid a second file read.
+                    let started = Instant::now();
                     let (head, saw_session_meta, saw_user_event) =
                         read_head_and_flags(&path, HEAD_RECORD_LIMIT)
                             .await
                             .unwrap_or((Vec::new(), false, false));
+                    log_head_read_timing(&path, started.elapsed());
                     // Apply filters: must have session meta and at least one user message event
                     if saw_session_meta && saw_user_event {
                         items.push(ConversationItem { path, head });
@@ -222,6 +224,23 @@ fn build_next_cursor(items: &[ConversationItem]) -> Option<Cursor> {
     Some(Cursor::new(ts, id))
 }
 
+/// Rollout files directly inside a day directory, ordered newest first. Files
+/// from the same second are ordered by UUID desc so the order is stable.
+async fn collect_rollout_files_desc(
+    day_path: &Path,
+) -> io::Result<Vec<(OffsetDateTime, Uuid, PathBuf)>> {
+    let mut day_files = collect_files(day_path, |name_str, path| {
+        if !name_str.starts_with("rollout-") || !name_str.ends_with(".jsonl") {
+            return None;
+        }
+
+        parse_timestamp_uuid_from_filename(name_str).map(|(ts, id)| (ts, id, path.to_path_buf()))
+    })
+    .await?;
+    day_files.sort_by_key(|(ts, sid, _path)| (Reverse(*ts), Reverse(*sid)));
+    Ok(day_files)
+}
+
 /// Collects immediate subdirectories of `parent`, parses their (string) names with `parse`,
 /// and returns them sorted descending by the parsed key.
 async fn collect_dirs_desc<T, F>(parent: &Path, parse: F) -> io::Result<Vec<(T, PathBuf)>>
@@ -286,23 +305,47 @@ fn parse_timestamp_uuid_from_filename(name: &str) -> Option<(OffsetDateTime, Uui
     Some((ts, uuid))
 }
 
+/// Per-file timing is emitted at debug level (enable with
+/// `RUST_LOG=codex_core::rollout=debug`); slow reads are always warned about.
+fn log_head_read_timing(path: &Path, elapsed: Duration) {
+    if elapsed >= SLOW_HEAD_READ {
+        tracing::warn!(
+            "slow rollout head read: {} took {elapsed:?}",
+            path.display()
+        );
+    } else {
+        tracing::debug!("rollout head read: {} took {elapsed:?}", path.display());
+    }
+}
+
 async fn read_head_and_flags(
     path: &Path,
     max_records: usize,
 ) -> io::Result<(Vec<serde_json::Value>, bool, bool)> {
-    use tokio::io::AsyncBufReadExt;
-
     let file = tokio::fs::File::open(path).await?;
-    let reader = tokio::io::BufReader::new(file);
-    let mut lines = reader.lines();
+    let mut reader = tokio::io::BufReader::new(file);
+    let mut line = Vec::new();
     let mut head: Vec<serde_json::Value> = Vec::new();
     let mut saw_session_meta = false;
     let mut saw_user_event = false;
 
     while head.len() < max_records {
-        let line_opt = lines.next_line().await?;
-        let Some(line) = line_opt else { break };
-        let trimmed = line.trim();
+        match read_bounded_line(&mut reader, &mut line, MAX_HEAD_LINE_BYTES).await? {
+            BoundedLine::Eof => break,
+            BoundedLine::TooLong => {
+                // A user message with a pasted image can exceed the cap; it
+                // still counts as the session's user turn.
+                if is_user_message_prefix(&line) {
+                    saw_user_event = true;
+                }
+                continue;
+            }
+            BoundedLine::Line => {}
+        }
+        let Ok(text) = std::str::from_utf8(&line) else {
+            continue;
+        };
+        let trimmed = text.trim();
         if trimmed.is_empty() {
             continue;
         }
@@ -339,6 +382,72 @@ async fn read_head_and_flags(
     Ok((head, saw_session_meta, saw_user_event))
 }
 
+/// Whether a truncated JSONL record is an `event_msg` carrying a
+/// `user_message`. Both tags are serialized ahead of the (potentially huge)
+/// message body, so they survive truncation.
+fn is_user_message_prefix(prefix: &[u8]) -> bool {
+    let contains = |needle: &[u8]| prefix.windows(needle.len()).any(|w| w == needle);
+    contains(br#""type":"event_msg""#) && contains(br#""payload":{"type":"user_message""#)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(super) enum BoundedLine {
+    /// A complete line (without its trailing newline) is in the buffer.
+    Line,
+    /// The line exceeded the byte cap; only its first `max_bytes` bytes are
+    /// in the buffer.
+    TooLong,
+    Eof,
+}
+
+/// Read the next line into `buf`, buffering at most `max_bytes` of it. The
+/// rest of an over-long line is consumed and dropped so a single giant record
+/// cannot force the whole line into memory; its prefix stays in `buf`.
+pub(super) async fn read_bounded_line<R>(
+    reader: &mut R,
+    buf: &mut Vec<u8>,
+    max_bytes: usize,
+) -> io::Result<BoundedLine>
+where
+    R: tokio::io::AsyncBufRead + Unpin,
+{
+    use tokio::io::AsyncBufReadExt;
+
+    buf.clear();
+    let mut read_any = false;
+    let mut too_long = false;
+    loop {
+        let available = reader.fill_buf().await?;
+        if available.is_empty() {
+            return Ok(match (read_any, too_long) {
+                (false, _) => BoundedLine::Eof,
+                (true, true) => BoundedLine::TooLong,
+                (true, false) => BoundedLine::Line,
+            });
+        }
+        read_any = true;
+        let newline = available.iter().position(|b| *b == b'\n');
+        let chunk = &available[..newline.unwrap_or(available.len())];
+        if !too_long {
+            if buf.len() + chunk.len() > max_bytes {
+                too_long = true;
+                buf.extend_from_slice(&chunk[..max_bytes - buf.len()]);
+            } else {
+                buf.extend_from_slice(chunk);
+            }
+        }
+        let consumed = chunk.len() + usize::from(newline.is_some());
+        reader.consume(consumed);
+        if newline.is_some() {
+            return Ok(if too_long {
+                BoundedLine::TooLong
+            } else {
+                BoundedLine::Line
+            });
+        }
+    }
+}
+
 /// Locate a recorded conversation rollout file by its UUID string using the existing
 /// paginated listing implementation. Returns `Ok(Some(path))` if found, `Ok(None)` if not present
 /// or the id is invalid.
@@ -363,7 +472,8 @@ pub async fn find_conversation_path_by_id_str(
     #[allow(clippy::unwrap_used)]
     let threads = NonZero::new(2).unwrap();
     let cancel = Arc::new(AtomicBool::new(false));
-    let exclude: Vec<String> = Vec::new();
+    // Trashed rollouts keep their id in the filename; never resume them in place.
+    let exclude: Vec<String> = vec![SESSIONS_TRASH_SUBDIR.to_string()];
     let compute_indices = false;
 
     let results = file_search::run(
@@ -383,3 +493,65 @@ pub async fn find_conversation_path_by_id_str(
         .next()
         .map(|m| root.join(m.path)))
 }
+
+/// Locate the newest recorded conversation that was started in `cwd` while
+/// `branch` was checked out, according to the git info captured in the
+/// session meta line. Returns `Ok(None)` when no rollout matches.
+pub async fn find_latest_conversation_path_for_branch(
+    codex_home: &Path,
+    cwd: &Path,
+    branch: &str,
+) -> io::Result<Option<PathBuf>> {
+    let root = codex_home.join(SESSIONS_SUBDIR);
+    if !root.exists() {
+        return Ok(None);
+    }
+    let canonical_cwd = canonicalize_or_self(cwd).await;
+
+    // Walk the whole tree once, newest first. Unlike the paginated listing
+    // this is not bounded by MAX_SCAN_FILES, so older sessions are found too.
+    for (_year, year_path) in collect_dirs_desc(&root, |s| s.parse::<u16>().ok()).await? {
+        for (_month, month_path) in collect_dirs_desc(&year_path, |s| s.parse::<u8>().ok()).await? {
+            for (_day, day_path) in collect_dirs_desc(&month_path, |s| s.parse::<u8>().ok()).await?
+            {
+                for (_ts, _sid, path) in collect_rollout_files_desc(&day_path).await? {
+                    let Ok((head, saw_session_meta, saw_user_event)) =
+                        read_head_and_flags(&path, HEAD_RECORD_LIMIT).await
+                    else {
+                        continue;
+                    };
+                    // Same filters as the listing: only resumable sessions.
+                    if saw_session_meta
+                        && saw_user_event
+                        && head_matches_branch(&head, &canonical_cwd, branch).await
+                    {
+                        return Ok(Some(path));
+                    }
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
+async fn head_matches_branch(head: &[serde_json::Value], cwd: &Path, branch: &str) -> bool {
+    let Some(meta_line) = head
+        .iter()
+        .find_map(|value| serde_json::from_value::<SessionMetaLine>(value.clone()).ok())
+    else {
+        return false;
+    };
+    if meta_line.git.as_ref().and_then(|git| git.branch.as_deref()) != Some(branch) {
+        return false;
+    }
+    meta_line.meta.cwd == cwd || canonicalize_or_self(&meta_line.meta.cwd).await == cwd
+}
+
+/// Resolve symlinks so a directory reached through a link matches the path
+/// recorded by an earlier session. Paths that no longer exist are compared
+/// as recorded.
+async fn canonicalize_or_self(path: &Path) -> PathBuf {
+    tokio::fs::canonicalize(path)
+        .await
+        .unwrap_or_else(|_| path.to_path_buf())
+}
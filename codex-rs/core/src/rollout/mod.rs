@@ -2,6 +2,9 @@
 
 pub const SESSIONS_SUBDIR: &str = "sessions";
 pub const ARCHIVED_SESSIONS_SUBDIR: &str = "archived_sessions";
+/// Directory under `sessions/` holding rollouts deleted from the resume
+/// picker; never searched when looking up sessions.
+pub const SESSIONS_TRASH_SUBDIR: &str = ".trash";
 
 pub mod list;
 pub(crate) mod policy;
@@ -9,6 +12,7 @@ pub mod recorder;
 
 pub use codex_protocol::protocol::SessionMeta;
 pub use list::find_conversation_path_by_id_str;
+pub use list::find_latest_conversation_path_for_branch;
 pub use recorder::RolloutRecorder;
 pub use recorder::RolloutRecorderParams;
 
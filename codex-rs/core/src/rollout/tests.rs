@@ -4,6 +4,7 @@ use std::fs::File;
 use std::fs::{self};
 use std::io::Write;
 use std::path::Path;
+use std::path::PathBuf;
 
 use tempfile::TempDir;
 use time::OffsetDateTime;
@@ -12,70 +13,150 @@ use time::format_description::FormatItem;
 use time::macros::format_description;
 use uuid::Uuid;
 
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::InputMessageKind;
+use codex_protocol::protocol::RolloutItem;
+use codex_protocol::protocol::RolloutLine;
+use codex_protocol::protocol::UserMessageEvent;
+
+use crate::rollout::list::BoundedLine;
 use crate::rollout::list::ConversationItem;
 use crate::rollout::list::ConversationsPage;
 use crate::rollout::list::Cursor;
+use crate::rollout::list::find_latest_conversation_path_for_branch;
 use crate::rollout::list::get_conversation;
 use crate::rollout::list::get_conversations;
+use crate::rollout::list::read_bounded_line;
+
+/// Optional changes to the rollout written by `write_rollout`.
+#[derive(Default)]
+struct RolloutSpec {
+    /// Working directory recorded in the session meta; defaults to ".".
+    cwd: Option<String>,
+    /// Git branch recorded in the session meta.
+    branch: Option<String>,
+    /// Raw JSONL records written before the session meta line.
+    leading: Vec<String>,
+    /// Replaces the default `user_message` event when set.
+    user_event: Option<String>,
+    /// Raw JSONL records written after the user event.
+    trailing: Vec<String>,
+}
 
-fn write_session_file(
+/// Write `sessions/YYYY/MM/DD/rollout-<ts>-<uuid>.jsonl` holding a session
+/// meta line and a user message event (enough to pass the listing filters),
+/// adjusted by `spec`. Returns the file path.
+fn write_rollout(
     root: &Path,
     ts_str: &str,
     uuid: Uuid,
-    num_records: usize,
-) -> std::io::Result<(OffsetDateTime, Uuid)> {
-    let format: &[FormatItem] =
-        format_description!("[year]-[month]-[day]T[hour]-[minute]-[second]");
-    let dt = PrimitiveDateTime::parse(ts_str, format)
-        .unwrap()
-        .assume_utc();
+    spec: RolloutSpec,
+) -> std::io::Result<PathBuf> {
     let dir = root
         .join("sessions")
-        .join(format!("{:04}", dt.year()))
-        .join(format!("{:02}", u8::from(dt.month())))
-        .join(format!("{:02}", dt.day()));
+        .join(&ts_str[0..4])
+        .join(&ts_str[5..7])
+        .join(&ts_str[8..10]);
     fs::create_dir_all(&dir)?;
+    let file_path = dir.join(format!("rollout-{ts_str}-{uuid}.jsonl"));
+    let mut file = File::create(&file_path)?;
 
-    let filename = format!("rollout-{ts_str}-{uuid}.jsonl");
-    let file_path = dir.join(filename);
-    let mut file = File::create(file_path)?;
+    for record in &spec.leading {
+        writeln!(file, "{record}")?;
+    }
 
+    let mut payload = serde_json::json!({
+        "id": uuid,
+        "timestamp": ts_str,
+        "instructions": null,
+        "cwd": spec.cwd.as_deref().unwrap_or("."),
+        "originator": "test_originator",
+        "cli_version": "test_version"
+    });
+    if let Some(branch) = &spec.branch {
+        payload["git"] = serde_json::json!({ "branch": branch });
+    }
     let meta = serde_json::json!({
         "timestamp": ts_str,
         "type": "session_meta",
-        "payload": {
-            "id": uuid,
-            "timestamp": ts_str,
-            "instructions": null,
-            "cwd": ".",
-            "originator": "test_originator",
-            "cli_version": "test_version"
-        }
+        "payload": payload
     });
     writeln!(file, "{meta}")?;
 
-    // Include at least one user message event to satisfy listing filters
-    let user_event = serde_json::json!({
-        "timestamp": ts_str,
-        "type": "event_msg",
-        "payload": {
-            "type": "user_message",
-            "message": "Hello from user",
-            "kind": "plain"
+    match spec.user_event {
+        Some(user_event) => writeln!(file, "{user_event}")?,
+        None => {
+            let user_event = serde_json::json!({
+                "timestamp": ts_str,
+                "type": "event_msg",
+                "payload": {
+                    "type": "user_message",
+                    "message": "Hello from user",
+                    "kind": "plain"
+                }
+            });
+            writeln!(file, "{user_event}")?;
         }
-    });
-    writeln!(file, "{user_event}")?;
-
-    for i in 0..num_records {
-        let rec = serde_json::json!({
-            "record_type": "response",
-            "index": i
-        });
-        writeln!(file, "{rec}")?;
     }
+
+    for record in &spec.trailing {
+        writeln!(file, "{record}")?;
+    }
+    Ok(file_path)
+}
+
+fn write_session_file(
+    root: &Path,
+    ts_str: &str,
+    uuid: Uuid,
+    num_records: usize,
+) -> std::io::Result<(OffsetDateTime, Uuid)> {
+    let format: &[FormatItem] =
+        format_description!("[year]-[month]-[day]T[hour]-[minute]-[second]");
+    let dt = PrimitiveDateTime::parse(ts_str, format)
+        .unwrap()
+        .assume_utc();
+    let trailing = (0..num_records)
+        .map(|i| {
+            serde_json::json!({
+                "record_type": "response",
+                "index": i
+            })
+            .to_string()
+        })
+        .collect();
+    write_rollout(
+        root,
+        ts_str,
+        uuid,
+        RolloutSpec {
+            trailing,
+            ..Default::default()
+        },
+    )?;
     Ok((dt, uuid))
 }
 
+/// Write a minimal rollout whose session meta records `cwd` and git `branch`.
+fn write_session_file_with_git(
+    root: &Path,
+    ts_str: &str,
+    uuid: Uuid,
+    cwd: &str,
+    branch: &str,
+) -> std::io::Result<PathBuf> {
+    write_rollout(
+        root,
+        ts_str,
+        uuid,
+        RolloutSpec {
+            cwd: Some(cwd.to_string()),
+            branch: Some(branch.to_string()),
+            ..Default::default()
+        },
+    )
+}
+
 #[tokio::test]
 async fn test_list_conversations_latest_first() {
     let temp = TempDir::new().unwrap();
@@ -443,3 +524,306 @@ async fn test_stable_ordering_same_second_pagination() {
     };
     assert_eq!(page2, expected_page2);
 }
+
+#[tokio::test]
+async fn test_find_latest_conversation_for_branch() {
+    let temp = TempDir::new().unwrap();
+    let home = temp.path();
+
+    let older_match = write_session_file_with_git(
+        home,
+        "2025-03-01T09-00-00",
+        Uuid::from_u128(1),
+        "/repo",
+        "feature",
+    )
+    .unwrap();
+    let newest_match = write_session_file_with_git(
+        home,
+        "2025-03-02T09-00-00",
+        Uuid::from_u128(2),
+        "/repo",
+        "feature",
+    )
+    .unwrap();
+    // Newer sessions on another branch or in another directory are skipped.
+    write_session_file_with_git(
+        home,
+        "2025-03-03T09-00-00",
+        Uuid::from_u128(3),
+        "/repo",
+        "main",
+    )
+    .unwrap();
+    write_session_file_with_git(
+        home,
+        "2025-03-04T09-00-00",
+        Uuid::from_u128(4),
+        "/other",
+        "feature",
+    )
+    .unwrap();
+
+    let found = find_latest_conversation_path_for_branch(home, Path::new("/repo"), "feature")
+        .await
+        .unwrap();
+    assert_eq!(found, Some(newest_match));
+    assert_ne!(found, Some(older_match));
+
+    let missing = find_latest_conversation_path_for_branch(home, Path::new("/repo"), "nope")
+        .await
+        .unwrap();
+    assert_eq!(missing, None);
+}
+
+#[tokio::test]
+async fn test_find_latest_conversation_for_branch_searches_past_scan_cap() {
+    let temp = TempDir::new().unwrap();
+    let home = temp.path();
+
+    // The only match is older than well over MAX_SCAN_FILES newer rollouts.
+    let oldest = write_session_file_with_git(
+        home,
+        "2025-05-01T00-00-00",
+        Uuid::from_u128(1),
+        "/repo",
+        "feature",
+    )
+    .unwrap();
+    for i in 1..150u32 {
+        let ts = format!("2025-05-01T{:02}-{:02}-00", i / 60, i % 60);
+        write_session_file_with_git(
+            home,
+            &ts,
+            Uuid::from_u128(u128::from(i) + 1),
+            "/repo",
+            "main",
+        )
+        .unwrap();
+    }
+
+    let found = find_latest_conversation_path_for_branch(home, Path::new("/repo"), "feature")
+        .await
+        .unwrap();
+    assert_eq!(found, Some(oldest));
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_find_latest_conversation_for_branch_normalizes_cwd() {
+    let temp = TempDir::new().unwrap();
+    let home = temp.path();
+    let repo = temp.path().join("repo");
+    fs::create_dir_all(&repo).unwrap();
+    let link = temp.path().join("repo-link");
+    std::os::unix::fs::symlink(&repo, &link).unwrap();
+
+    let recorded = write_session_file_with_git(
+        home,
+        "2025-03-01T09-00-00",
+        Uuid::from_u128(1),
+        repo.to_str().unwrap(),
+        "feature",
+    )
+    .unwrap();
+
+    let with_slash = format!("{}/", repo.display());
+    let found = find_latest_conversation_path_for_branch(home, Path::new(&with_slash), "feature")
+        .await
+        .unwrap();
+    assert_eq!(found, Some(recorded.clone()));
+
+    let found = find_latest_conversation_path_for_branch(home, &link, "feature")
+        .await
+        .unwrap();
+    assert_eq!(found, Some(recorded));
+}
+
+#[tokio::test]
+async fn test_bounded_line_reader_skips_oversized_lines() {
+    let input = format!("{}\nsmall\n{}", "x".repeat(64), "y".repeat(64));
+    let mut reader = tokio::io::BufReader::with_capacity(8, input.as_bytes());
+    let mut line = Vec::new();
+
+    let first = read_bounded_line(&mut reader, &mut line, 16).await.unwrap();
+    assert_eq!(first, BoundedLine::TooLong);
+    assert_eq!(line, "x".repeat(16).as_bytes());
+
+    let second = read_bounded_line(&mut reader, &mut line, 16).await.unwrap();
+    assert_eq!(second, BoundedLine::Line);
+    assert_eq!(line, b"small");
+
+    // An oversized final line without a trailing newline is still skipped.
+    let third = read_bounded_line(&mut reader, &mut line, 16).await.unwrap();
+    assert_eq!(third, BoundedLine::TooLong);
+    let eof = read_bounded_line(&mut reader, &mut line, 16).await.unwrap();
+    assert_eq!(eof, BoundedLine::Eof);
+}
+
+#[tokio::test]
+async fn test_list_conversations_skips_huge_record() {
+    let temp = TempDir::new().unwrap();
+    let home = temp.path();
+    let uuid = Uuid::from_u128(7);
+    let ts = "2025-04-01T10-00-00";
+    // A multi-megabyte record (think inline base64 image) followed by a
+    // normal message; the listing skips the former and keeps the latter.
+    let huge = serde_json::json!({
+        "timestamp": ts,
+        "type": "response_item",
+        "payload": {
+            "type": "message",
+            "role": "user",
+            "content": [{ "type": "input_text", "text": "a".repeat(4 * 1024 * 1024) }]
+        }
+    });
+    let small = serde_json::json!({
+        "timestamp": ts,
+        "type": "response_item",
+        "payload": {
+            "type": "message",
+            "role": "user",
+            "content": [{ "type": "input_text", "text": "after the image" }]
+        }
+    });
+    write_rollout(
+        home,
+        ts,
+        uuid,
+        RolloutSpec {
+            trailing: vec![huge.to_string(), small.to_string()],
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let page = get_conversations(home, 10, None).await.unwrap();
+    assert_eq!(page.items.len(), 1);
+    let head = &page.items[0].head;
+    assert_eq!(head.len(), 2);
+    assert_eq!(head[0]["id"], serde_json::json!(uuid));
+    assert_eq!(
+        head[1]["content"][0]["text"],
+        serde_json::json!("after the image")
+    );
+}
+
+#[derive(Clone, Default)]
+struct CapturedLogs(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+impl Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_list_conversations_logs_head_read_timing_at_debug() {
+    let temp = TempDir::new().unwrap();
+    let home = temp.path();
+    let uuid = Uuid::from_u128(8);
+    let ts = "2025-04-02T10-00-00";
+    write_session_file(home, ts, uuid, 1).unwrap();
+
+    let logs = CapturedLogs::default();
+    let writer = logs.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::DEBUG)
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    get_conversations(home, 10, None).await.unwrap();
+
+    let output = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+    assert!(
+        output.contains("rollout head read: ")
+            && output.contains(&format!("rollout-{ts}-{uuid}.jsonl took")),
+        "missing timing event in: {output}"
+    );
+}
+
+#[tokio::test]
+async fn test_list_conversations_skips_leading_schema_record() {
+    let temp = TempDir::new().unwrap();
+    let home = temp.path();
+    let uuid = Uuid::from_u128(9);
+    let ts = "2025-04-03T10-00-00";
+    // A schema/version record ahead of the session meta must not hide the
+    // header: the meta line still becomes head[0].
+    let schema = serde_json::json!({ "type": "schema", "version": 2 });
+    let message = serde_json::json!({
+        "timestamp": ts,
+        "type": "response_item",
+        "payload": {
+            "type": "message",
+            "role": "user",
+            "content": [{ "type": "input_text", "text": "refactor the parser" }]
+        }
+    });
+    write_rollout(
+        home,
+        ts,
+        uuid,
+        RolloutSpec {
+            cwd: Some("/repo".to_string()),
+            leading: vec![schema.to_string()],
+            trailing: vec![message.to_string()],
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let page = get_conversations(home, 10, None).await.unwrap();
+    assert_eq!(page.items.len(), 1);
+    let head = &page.items[0].head;
+    assert_eq!(head[0]["id"], serde_json::json!(uuid));
+    assert_eq!(head[0]["cwd"], serde_json::json!("/repo"));
+    assert_eq!(
+        head[1]["content"][0]["text"],
+        serde_json::json!("refactor the parser")
+    );
+}
+
+#[tokio::test]
+async fn test_list_conversations_keeps_session_whose_user_message_is_oversized() {
+    let temp = TempDir::new().unwrap();
+    let home = temp.path();
+    let uuid = Uuid::from_u128(10);
+    let ts = "2025-04-04T10-00-00";
+    // The only user_message carries a pasted image as a data URL, well past
+    // the head line cap. Serialize it the way the recorder does so the tags
+    // precede the payload body.
+    let user_event = serde_json::to_string(&RolloutLine {
+        timestamp: ts.to_string(),
+        item: RolloutItem::EventMsg(EventMsg::UserMessage(UserMessageEvent {
+            message: "what is in this screenshot?".to_string(),
+            kind: Some(InputMessageKind::Plain),
+            images: Some(vec![format!(
+                "data:image/png;base64,{}",
+                "A".repeat(1024 * 1024)
+            )]),
+        })),
+    })
+    .unwrap();
+    let path = write_rollout(
+        home,
+        ts,
+        uuid,
+        RolloutSpec {
+            user_event: Some(user_event),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let page = get_conversations(home, 10, None).await.unwrap();
+    let paths: Vec<_> = page.items.iter().map(|item| item.path.clone()).collect();
+    assert_eq!(paths, vec![path]);
+}
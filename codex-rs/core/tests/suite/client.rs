@@ -291,6 +291,84 @@ async fn resume_includes_initial_messages_and_sends_prior_items() {
     assert_eq!(request_body["input"], expected_input);
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn resume_restores_recorded_model_when_enabled() {
+    // A rollout whose latest turn ran on a different model than the default.
+    let tmpdir = TempDir::new().unwrap();
+    let session_path = tmpdir.path().join("resume-session.jsonl");
+    let turn = codex_protocol::protocol::TurnContextItem {
+        cwd: tmpdir.path().to_path_buf(),
+        approval_policy: codex_core::protocol::AskForApproval::OnRequest,
+        sandbox_policy: codex_core::protocol::SandboxPolicy::ReadOnly,
+        model: "gpt-4.1".to_string(),
+        effort: Some(codex_protocol::config_types::ReasoningEffort::High),
+        summary: codex_protocol::config_types::ReasoningSummary::Auto,
+    };
+    let lines = [
+        json!({
+            "timestamp": "2024-01-01T00:00:00.000Z",
+            "type": "session_meta",
+            "payload": {
+                "id": Uuid::new_v4(),
+                "timestamp": "2024-01-01T00:00:00Z",
+                "instructions": null,
+                "cwd": ".",
+                "originator": "test_originator",
+                "cli_version": "test_version"
+            }
+        }),
+        json!({
+            "timestamp": "2024-01-01T00:00:01.000Z",
+            "type": "turn_context",
+            "payload": turn
+        }),
+    ];
+    let contents: String = lines.iter().map(|line| format!("{line}\n")).collect();
+    std::fs::write(&session_path, contents).unwrap();
+
+    let codex_home = TempDir::new().unwrap();
+    let mut config = load_default_config_for_test(&codex_home);
+    let default_model = config.model.clone();
+    assert_ne!(default_model, "gpt-4.1");
+    let conversation_manager =
+        ConversationManager::with_auth(CodexAuth::from_api_key("Test API Key"));
+    let auth_manager =
+        codex_core::AuthManager::from_auth_for_testing(CodexAuth::from_api_key("Test API Key"));
+
+    // By default the current configuration wins.
+    let kept = conversation_manager
+        .resume_conversation_from_rollout(
+            config.clone(),
+            session_path.clone(),
+            auth_manager.clone(),
+        )
+        .await
+        .expect("resume conversation");
+    assert_eq!(kept.session_configured.model, default_model);
+
+    // Token limits follow the restored model while explicit overrides from
+    // config.toml survive the model switch.
+    let mut switched = config.clone();
+    switched.model_overrides.supports_reasoning_summaries = Some(true);
+    switched.restore_recorded_model(&turn.model, turn.effort);
+    assert_ne!(config.model_context_window, Some(1_047_576));
+    assert_eq!(switched.model_context_window, Some(1_047_576));
+    assert_eq!(switched.model_max_output_tokens, Some(32_768));
+    assert_eq!(switched.model_family.family, "gpt-4.1");
+    assert!(switched.model_family.supports_reasoning_summaries);
+
+    config.resume_restores_model = true;
+    let restored = conversation_manager
+        .resume_conversation_from_rollout(config, session_path, auth_manager)
+        .await
+        .expect("resume conversation");
+    assert_eq!(restored.session_configured.model, "gpt-4.1");
+    assert_eq!(
+        restored.session_configured.reasoning_effort,
+        Some(codex_protocol::config_types::ReasoningEffort::High)
+    );
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn includes_conversation_id_and_model_headers_in_request() {
     skip_if_no_network!();
@@ -48,3 +48,24 @@ async fn find_locates_rollout_file_by_id() {
 
     assert_eq!(found.unwrap(), expected);
 }
+
+#[tokio::test]
+async fn find_skips_trashed_rollouts() {
+    let home = TempDir::new().unwrap();
+    let id = Uuid::new_v4();
+    let rollout = write_minimal_rollout_with_id(&home, id);
+
+    let trash = home
+        .path()
+        .join(codex_core::SESSIONS_SUBDIR)
+        .join(codex_core::SESSIONS_TRASH_SUBDIR);
+    std::fs::create_dir_all(&trash).unwrap();
+    let file_name = rollout.file_name().unwrap().to_string_lossy();
+    std::fs::rename(&rollout, trash.join(format!("1700000000-{file_name}"))).unwrap();
+
+    let found = find_conversation_path_by_id_str(home.path(), &id.to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(found, None);
+}
@@ -21,6 +21,11 @@ pub struct Cli {
     #[arg(long = "oss", default_value_t = false)]
     pub oss: bool,
 
+    /// Model provider to use, looked up by id among the built-in providers
+    /// and any defined under `model_providers` in config.toml.
+    #[arg(long = "provider", value_name = "ID", conflicts_with = "oss")]
+    pub model_provider: Option<String>,
+
     /// Select the sandbox policy to use when executing model-generated shell
     /// commands.
     #[arg(long = "sandbox", short = 's', value_enum)]
@@ -94,7 +99,8 @@ pub struct Cli {
 
 #[derive(Debug, clap::Subcommand)]
 pub enum Command {
-    /// Resume a previous session by id or pick the most recent with --last.
+    /// Resume a previous session by id, the most recent with --last, or the
+    /// most recent on a git branch with --branch.
     Resume(ResumeArgs),
 }
 
@@ -109,6 +115,15 @@ pub struct ResumeArgs {
     #[arg(long = "last", default_value_t = false, conflicts_with = "session_id")]
     pub last: bool,
 
+    /// Resume the most recent session started in the current directory while
+    /// the given git branch was checked out.
+    #[arg(
+        long = "branch",
+        value_name = "BRANCH",
+        conflicts_with_all = ["session_id", "last"]
+    )]
+    pub branch: Option<String>,
+
     /// Prompt to send after resuming the session. If `-` is used, read from stdin.
     #[arg(value_name = "PROMPT")]
     pub prompt: Option<String>,
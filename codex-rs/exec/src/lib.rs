@@ -38,6 +38,7 @@ use crate::event_processor::CodexStatus;
 use crate::event_processor::EventProcessor;
 use crate::event_processor_with_json_output::EventProcessorWithJsonOutput;
 use codex_core::find_conversation_path_by_id_str;
+use codex_core::find_latest_conversation_path_for_branch;
 
 pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()> {
     let Cli {
@@ -45,6 +46,7 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
         images,
         model: model_cli_arg,
         oss,
+        model_provider: model_provider_cli_arg,
         config_profile,
         full_auto,
         dangerously_bypass_approvals_and_sandbox,
@@ -150,7 +152,7 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
     let model_provider = if oss {
         Some(BUILT_IN_OSS_MODEL_PROVIDER_ID.to_string())
     } else {
-        None // No specific model provider override.
+        model_provider_cli_arg
     };
 
     // Load configuration and determine approval policy
@@ -359,6 +361,11 @@ async fn resolve_resume_path(
                 Ok(None)
             }
         }
+    } else if let Some(branch) = args.branch.as_deref() {
+        let path =
+            find_latest_conversation_path_for_branch(&config.codex_home, &config.cwd, branch)
+                .await?;
+        Ok(path)
     } else if let Some(id_str) = args.session_id.as_deref() {
         let path = find_conversation_path_by_id_str(&config.codex_home, id_str).await?;
         Ok(path)
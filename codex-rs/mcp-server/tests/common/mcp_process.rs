@@ -213,6 +213,18 @@ impl McpProcess {
         .await
     }
 
+    /// Send a `ping` JSON-RPC request.
+    pub async fn send_ping_request(&mut self) -> anyhow::Result<i64> {
+        self.send_request(mcp_types::PingRequest::METHOD, None)
+            .await
+    }
+
+    /// Send a `tools/list` JSON-RPC request.
+    pub async fn send_list_tools_request(&mut self) -> anyhow::Result<i64> {
+        self.send_request(mcp_types::ListToolsRequest::METHOD, None)
+            .await
+    }
+
     /// Send a `newConversation` JSON-RPC request.
     pub async fn send_new_conversation_request(
         &mut self,
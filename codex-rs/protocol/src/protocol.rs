@@ -890,6 +890,19 @@ impl InitialHistory {
         }
     }
 
+    /// Settings recorded for the most recent turn, if the history has any.
+    pub fn last_turn_context(&self) -> Option<&TurnContextItem> {
+        let items = match self {
+            InitialHistory::New => return None,
+            InitialHistory::Resumed(resumed) => &resumed.history,
+            InitialHistory::Forked(items) => items,
+        };
+        items.iter().rev().find_map(|item| match item {
+            RolloutItem::TurnContext(turn) => Some(turn),
+            _ => None,
+        })
+    }
+
     pub fn get_event_msgs(&self) -> Option<Vec<EventMsg>> {
         match self {
             InitialHistory::New => None,
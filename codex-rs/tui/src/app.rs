@@ -72,7 +72,7 @@ impl App {
     pub async fn run(
         tui: &mut tui::Tui,
         auth_manager: Arc<AuthManager>,
-        config: Config,
+        mut config: Config,
         active_profile: Option<String>,
         initial_prompt: Option<String>,
         initial_images: Vec<PathBuf>,
@@ -110,6 +110,11 @@ impl App {
                     .wrap_err_with(|| {
                         format!("Failed to resume session from {}", path.display())
                     })?;
+                if config.resume_restores_model {
+                    // Keep the UI in sync with the model the session resumed on.
+                    let configured = &resumed.session_configured;
+                    config.restore_recorded_model(&configured.model, configured.reasoning_effort);
+                }
                 let init = crate::chatwidget::ChatWidgetInit {
                     config: config.clone(),
                     frame_requester: tui.frame_requester(),
@@ -125,6 +130,22 @@ impl App {
                     resumed.session_configured,
                 )
             }
+            ResumeSelection::Fork(path) => {
+                let forked = conversation_manager
+                    .fork_conversation_from_rollout(config.clone(), path.clone())
+                    .await
+                    .wrap_err_with(|| format!("Failed to fork session from {}", path.display()))?;
+                let init = crate::chatwidget::ChatWidgetInit {
+                    config: config.clone(),
+                    frame_requester: tui.frame_requester(),
+                    app_event_tx: app_event_tx.clone(),
+                    initial_prompt: initial_prompt.clone(),
+                    initial_images: initial_images.clone(),
+                    enhanced_keys_supported,
+                    auth_manager: auth_manager.clone(),
+                };
+                ChatWidget::new_from_existing(init, forked.conversation, forked.session_configured)
+            }
         };
 
         let file_search = FileSearchManager::new(config.cwd.clone(), app_event_tx.clone());
@@ -27,6 +27,16 @@ pub struct Cli {
     #[clap(skip)]
     pub resume_session_id: Option<String>,
 
+    /// Internal: resume the newest session recorded in the working directory
+    /// on this git branch. Set by `codex resume --branch <BRANCH>`.
+    #[clap(skip)]
+    pub resume_branch: Option<String>,
+
+    /// Internal: look up sessions under this Codex home instead of the
+    /// active one. Set by `codex resume --sessions-from <CODEX_HOME>`.
+    #[clap(skip)]
+    pub resume_sessions_home: Option<PathBuf>,
+
     /// Model the agent should use.
     #[arg(long, short = 'm')]
     pub model: Option<String>,
@@ -37,6 +47,12 @@ pub struct Cli {
     #[arg(long = "oss", default_value_t = false)]
     pub oss: bool,
 
+    /// Model provider to use, looked up by id among the built-in providers
+    /// and any defined under `model_providers` in config.toml. Equivalent to
+    /// -c model_provider=<ID>.
+    #[arg(long = "provider", value_name = "ID", conflicts_with = "oss")]
+    pub model_provider: Option<String>,
+
     /// Configuration profile from config.toml to specify default options.
     #[arg(long = "profile", short = 'p')]
     pub config_profile: Option<String>,
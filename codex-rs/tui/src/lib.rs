@@ -17,6 +17,7 @@ use codex_core::config::find_codex_home;
 use codex_core::config::load_config_as_toml_with_cli_overrides;
 use codex_core::config::persist_model_selection;
 use codex_core::find_conversation_path_by_id_str;
+use codex_core::find_latest_conversation_path_for_branch;
 use codex_core::protocol::AskForApproval;
 use codex_core::protocol::SandboxPolicy;
 use codex_ollama::DEFAULT_OSS_MODEL;
@@ -24,6 +25,7 @@ use codex_protocol::config_types::SandboxMode;
 use codex_protocol::mcp_protocol::AuthMode;
 use std::fs::OpenOptions;
 use std::path::PathBuf;
+use std::time::Duration;
 use tracing::error;
 use tracing_appender::non_blocking;
 use tracing_subscriber::EnvFilter;
@@ -58,6 +60,8 @@ mod pager_overlay;
 mod render;
 mod resume_picker;
 mod session_log;
+mod session_tags;
+mod session_trash;
 mod shimmer;
 mod slash_command;
 mod status;
@@ -119,7 +123,7 @@ pub async fn run_main(
     let model_provider_override = if cli.oss {
         Some(BUILT_IN_OSS_MODEL_PROVIDER_ID.to_owned())
     } else {
-        None
+        cli.model_provider.clone()
     };
 
     // canonicalize the cwd
@@ -348,17 +352,34 @@ async fn run_ratatui_app(
         }
     }
 
-    // Determine resume behavior: explicit id, then resume last, then picker.
+    // Determine resume behavior: explicit id, then newest on a branch, then
+    // resume last, then picker. `codex resume --sessions-from` points every
+    // lookup at another Codex home's recorded sessions.
+    let sessions_home = cli
+        .resume_sessions_home
+        .clone()
+        .unwrap_or_else(|| config.codex_home.clone());
     let resume_selection = if let Some(id_str) = cli.resume_session_id.as_deref() {
-        match find_conversation_path_by_id_str(&config.codex_home, id_str).await? {
+        match find_conversation_path_by_id_str(&sessions_home, id_str).await? {
             Some(path) => resume_picker::ResumeSelection::Resume(path),
             None => {
                 error!("Error finding conversation path: {id_str}");
                 resume_picker::ResumeSelection::StartFresh
             }
         }
+    } else if let Some(branch) = cli.resume_branch.as_deref() {
+        match find_latest_conversation_path_for_branch(&sessions_home, &config.cwd, branch).await? {
+            Some(path) => resume_picker::ResumeSelection::Resume(path),
+            None => {
+                error!(
+                    "No recorded session found for branch {branch} in {}",
+                    config.cwd.display()
+                );
+                resume_picker::ResumeSelection::StartFresh
+            }
+        }
     } else if cli.resume_last {
-        match RolloutRecorder::list_conversations(&config.codex_home, 1, None).await {
+        match RolloutRecorder::list_conversations(&sessions_home, 1, None).await {
             Ok(page) => page
                 .items
                 .first()
@@ -367,7 +388,18 @@ async fn run_ratatui_app(
             Err(_) => resume_picker::ResumeSelection::StartFresh,
         }
     } else if cli.resume_picker {
-        match resume_picker::run_resume_picker(&mut tui, &config.codex_home).await? {
+        match resume_picker::run_resume_picker(
+            &mut tui,
+            &sessions_home,
+            Duration::from_secs(
+                config
+                    .tui_session_trash_retention_days
+                    .saturating_mul(24 * 60 * 60),
+            ),
+            config.tui_resume_filter_case,
+        )
+        .await?
+        {
             resume_picker::ResumeSelection::Exit => {
                 restore();
                 session_log::log_session_end();
@@ -1,14 +1,20 @@
 use std::collections::HashSet;
 use std::path::Path;
 use std::path::PathBuf;
+use std::process::Command;
+use std::process::Stdio;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
 
 use chrono::DateTime;
+use chrono::NaiveDateTime;
 use chrono::Utc;
 use codex_core::ConversationItem;
 use codex_core::ConversationsPage;
 use codex_core::Cursor;
 use codex_core::RolloutRecorder;
+use codex_core::config_types::FilterCase;
 use color_eyre::eyre::Result;
 use crossterm::event::KeyCode;
 use crossterm::event::KeyEvent;
@@ -23,10 +29,15 @@ use tokio::sync::mpsc;
 use tokio_stream::StreamExt;
 use tokio_stream::wrappers::UnboundedReceiverStream;
 
+use crate::session_tags::SessionTags;
+use crate::session_tags::normalize_tag;
+use crate::session_trash;
+use crate::session_trash::TrashedSession;
 use crate::text_formatting::truncate_text;
 use crate::tui::FrameRequester;
 use crate::tui::Tui;
 use crate::tui::TuiEvent;
+use crate::version::CODEX_CLI_VERSION;
 use codex_protocol::models::ContentItem;
 use codex_protocol::models::ResponseItem;
 use codex_protocol::protocol::InputMessageKind;
@@ -34,11 +45,15 @@ use codex_protocol::protocol::USER_MESSAGE_BEGIN;
 
 const PAGE_SIZE: usize = 25;
 const LOAD_NEAR_THRESHOLD: usize = 5;
+/// Rollouts modified within this window are flagged as possibly in use.
+const ACTIVE_WRITE_WINDOW: Duration = Duration::from_secs(10);
 
 #[derive(Debug, Clone)]
 pub enum ResumeSelection {
     StartFresh,
     Resume(PathBuf),
+    /// Start a new session seeded with the selected session's history.
+    Fork(PathBuf),
     Exit,
 }
 
@@ -62,8 +77,20 @@ enum BackgroundEvent {
 
 /// Interactive session picker that lists recorded rollout files with simple
 /// search and pagination. Shows the first user input as the preview, relative
-/// time (e.g., "5 seconds ago"), and the absolute path.
-pub async fn run_resume_picker(tui: &mut Tui, codex_home: &Path) -> Result<ResumeSelection> {
+/// time (e.g., "5 seconds ago"), and the absolute path. Sessions deleted
+/// from the picker sit in the trash for `trash_retention` before being purged.
+pub async fn run_resume_picker(
+    tui: &mut Tui,
+    codex_home: &Path,
+    trash_retention: Duration,
+    filter_case: FilterCase,
+) -> Result<ResumeSelection> {
+    match session_trash::purge_expired(codex_home, trash_retention, SystemTime::now()) {
+        Ok(0) => {}
+        Ok(removed) => tracing::info!("purged {removed} expired session(s) from the trash"),
+        Err(err) => tracing::warn!("failed to purge session trash: {err}"),
+    }
+
     let alt = AltScreenGuard::enter(tui);
     let (bg_tx, bg_rx) = mpsc::unbounded_channel();
 
@@ -90,6 +117,7 @@ pub async fn run_resume_picker(tui: &mut Tui, codex_home: &Path) -> Result<Resum
         alt.tui.frame_requester(),
         page_loader,
     );
+    state.filter_case = filter_case;
     state.load_initial_page().await?;
     state.request_frame();
 
@@ -163,6 +191,25 @@ struct PickerState {
     next_search_token: usize,
     page_loader: PageLoader,
     view_rows: Option<usize>,
+    tags: SessionTags,
+    /// Tag being typed for the selected session after pressing Ctrl+T.
+    tag_input: Option<String>,
+    /// Most recent deletion, restorable with Ctrl+U.
+    last_deleted: Option<DeletedSession>,
+    /// One-off message shown in place of the key hints until the next key.
+    status: Option<String>,
+    /// Session awaiting a second Enter because `resume_warning` flagged it.
+    pending_resume: Option<PathBuf>,
+    filter_case: FilterCase,
+    /// Whether the hint line lists the extra actions instead of the basics.
+    show_more_keys: bool,
+}
+
+/// A row removed from the list because its rollout was moved to the trash.
+struct DeletedSession {
+    trashed: TrashedSession,
+    row: Row,
+    index: usize,
 }
 
 struct PaginationState {
@@ -219,10 +266,19 @@ struct Row {
     path: PathBuf,
     preview: String,
     ts: Option<DateTime<Utc>>,
+    tags: Vec<String>,
+    /// Working directory recorded in the session meta.
+    cwd: Option<PathBuf>,
+    /// Version of Codex that recorded the session.
+    cli_version: Option<String>,
+    /// The rollout was modified moments ago, so another Codex process may
+    /// still be writing to it.
+    active: bool,
 }
 
 impl PickerState {
     fn new(codex_home: PathBuf, requester: FrameRequester, page_loader: PageLoader) -> Self {
+        let tags = SessionTags::load(&codex_home);
         Self {
             codex_home,
             requester,
@@ -243,6 +299,13 @@ impl PickerState {
             next_search_token: 0,
             page_loader,
             view_rows: None,
+            tags,
+            tag_input: None,
+            last_deleted: None,
+            status: None,
+            pending_resume: None,
+            filter_case: FilterCase::default(),
+            show_more_keys: false,
         }
     }
 
@@ -251,6 +314,13 @@ impl PickerState {
     }
 
     async fn handle_key(&mut self, key: KeyEvent) -> Result<Option<ResumeSelection>> {
+        if self.status.take().is_some() {
+            self.request_frame();
+        }
+        let pending_resume = self.pending_resume.take();
+        if self.tag_input.is_some() {
+            return Ok(self.handle_tag_input_key(key));
+        }
         match key.code {
             KeyCode::Esc => return Ok(Some(ResumeSelection::StartFresh)),
             KeyCode::Char('c')
@@ -262,9 +332,22 @@ impl PickerState {
             }
             KeyCode::Enter => {
                 if let Some(row) = self.filtered_rows.get(self.selected) {
-                    return Ok(Some(ResumeSelection::Resume(row.path.clone())));
+                    let path = row.path.clone();
+                    let confirmed = pending_resume.as_ref() == Some(&path);
+                    if !confirmed && let Some(warning) = resume_warning(row) {
+                        self.status =
+                            Some(format!("{warning} Press Enter again to resume anyway."));
+                        self.pending_resume = Some(path);
+                        self.request_frame();
+                        return Ok(None);
+                    }
+                    return Ok(Some(ResumeSelection::Resume(path)));
                 }
             }
+            KeyCode::Tab => {
+                self.show_more_keys = !self.show_more_keys;
+                self.request_frame();
+            }
             KeyCode::Up => {
                 if self.selected > 0 {
                     self.selected -= 1;
@@ -303,6 +386,46 @@ impl PickerState {
                 new_query.pop();
                 self.set_query(new_query);
             }
+            KeyCode::Char('f')
+                if key
+                    .modifiers
+                    .contains(crossterm::event::KeyModifiers::CONTROL) =>
+            {
+                if let Some(row) = self.filtered_rows.get(self.selected) {
+                    return Ok(Some(ResumeSelection::Fork(row.path.clone())));
+                }
+            }
+            KeyCode::Char('t')
+                if key
+                    .modifiers
+                    .contains(crossterm::event::KeyModifiers::CONTROL) =>
+            {
+                if self.filtered_rows.get(self.selected).is_some() {
+                    self.tag_input = Some(String::new());
+                    self.request_frame();
+                }
+            }
+            KeyCode::Char('d')
+                if key
+                    .modifiers
+                    .contains(crossterm::event::KeyModifiers::CONTROL) =>
+            {
+                self.delete_selected();
+            }
+            KeyCode::Char('u')
+                if key
+                    .modifiers
+                    .contains(crossterm::event::KeyModifiers::CONTROL) =>
+            {
+                self.undo_delete();
+            }
+            KeyCode::Char('o')
+                if key
+                    .modifiers
+                    .contains(crossterm::event::KeyModifiers::CONTROL) =>
+            {
+                self.open_selected_cwd();
+            }
             KeyCode::Char(c) => {
                 // basic text input for search
                 if !key
@@ -320,6 +443,132 @@ impl PickerState {
         Ok(None)
     }
 
+    fn handle_tag_input_key(&mut self, key: KeyEvent) -> Option<ResumeSelection> {
+        match key.code {
+            KeyCode::Char('c')
+                if key
+                    .modifiers
+                    .contains(crossterm::event::KeyModifiers::CONTROL) =>
+            {
+                return Some(ResumeSelection::Exit);
+            }
+            KeyCode::Esc => {
+                self.tag_input = None;
+            }
+            KeyCode::Enter => {
+                self.apply_tag_input();
+            }
+            KeyCode::Backspace => {
+                if let Some(input) = self.tag_input.as_mut() {
+                    input.pop();
+                }
+            }
+            KeyCode::Char(c)
+                if !key
+                    .modifiers
+                    .contains(crossterm::event::KeyModifiers::CONTROL)
+                    && !key.modifiers.contains(crossterm::event::KeyModifiers::ALT) =>
+            {
+                if let Some(input) = self.tag_input.as_mut() {
+                    input.push(c);
+                }
+            }
+            _ => {}
+        }
+        self.request_frame();
+        None
+    }
+
+    /// Toggle the typed tag on the selected session and persist it.
+    fn apply_tag_input(&mut self) {
+        let Some(input) = self.tag_input.take() else {
+            return;
+        };
+        let Some(path) = self
+            .filtered_rows
+            .get(self.selected)
+            .map(|row| row.path.clone())
+        else {
+            return;
+        };
+        match self.tags.toggle(&path, &input) {
+            Ok(tags) => {
+                for row in self.all_rows.iter_mut().filter(|row| row.path == path) {
+                    row.tags = tags.clone();
+                }
+                self.apply_filter();
+            }
+            Err(err) => tracing::warn!("failed to save session tags: {err}"),
+        }
+    }
+
+    /// Open the selected session's working directory in the file manager.
+    fn open_selected_cwd(&mut self) {
+        let Some(row) = self.filtered_rows.get(self.selected) else {
+            return;
+        };
+        let message = match row.cwd.as_deref() {
+            None => Some("This session did not record a working directory".to_string()),
+            Some(cwd) if !cwd.is_dir() => Some(format!("{} no longer exists", cwd.display())),
+            Some(cwd) => open_dir_command(cwd)
+                .stdin(Stdio::null())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn()
+                .map(|mut child| {
+                    // Reap the opener so it does not linger as a zombie.
+                    std::thread::spawn(move || {
+                        let _ = child.wait();
+                    });
+                })
+                .err()
+                .map(|err| format!("Failed to open {}: {err}", cwd.display())),
+        };
+        self.status = message;
+        self.request_frame();
+    }
+
+    /// Move the selected session to the trash and drop it from the list.
+    fn delete_selected(&mut self) {
+        let Some(path) = self
+            .filtered_rows
+            .get(self.selected)
+            .map(|row| row.path.clone())
+        else {
+            return;
+        };
+        let Some(index) = self.all_rows.iter().position(|row| row.path == path) else {
+            return;
+        };
+        match session_trash::move_to_trash(&self.codex_home, &path, SystemTime::now()) {
+            Ok(trashed) => {
+                let row = self.all_rows.remove(index);
+                self.last_deleted = Some(DeletedSession {
+                    trashed,
+                    row,
+                    index,
+                });
+                self.apply_filter();
+            }
+            Err(err) => tracing::warn!("failed to move session to trash: {err}"),
+        }
+    }
+
+    /// Restore the most recently deleted session to its original location.
+    fn undo_delete(&mut self) {
+        let Some(deleted) = self.last_deleted.take() else {
+            return;
+        };
+        match session_trash::restore(&deleted.trashed) {
+            Ok(()) => {
+                let index = deleted.index.min(self.all_rows.len());
+                self.all_rows.insert(index, deleted.row);
+                self.apply_filter();
+            }
+            Err(err) => tracing::warn!("failed to restore session from trash: {err}"),
+        }
+    }
+
     async fn load_initial_page(&mut self) -> Result<()> {
         let page = RolloutRecorder::list_conversations(&self.codex_home, PAGE_SIZE, None).await?;
         self.reset_pagination();
@@ -378,8 +627,12 @@ impl PickerState {
         }
 
         let rows = rows_from_items(page.items);
-        for row in rows {
+        for mut row in rows {
             if self.seen_paths.insert(row.path.clone()) {
+                row.tags = self.tags.get(&row.path);
+                row.active = std::fs::metadata(&row.path)
+                    .and_then(|meta| meta.modified())
+                    .is_ok_and(|modified| is_recently_written(modified, SystemTime::now()));
                 self.all_rows.push(row);
             }
         }
@@ -391,11 +644,11 @@ impl PickerState {
         if self.query.is_empty() {
             self.filtered_rows = self.all_rows.clone();
         } else {
-            let q = self.query.to_lowercase();
+            let filter = RowFilter::parse(&self.query, self.filter_case);
             self.filtered_rows = self
                 .all_rows
                 .iter()
-                .filter(|r| r.preview.to_lowercase().contains(&q))
+                .filter(|r| filter.matches(r))
                 .cloned()
                 .collect();
         }
@@ -559,6 +812,61 @@ impl PickerState {
     }
 }
 
+/// Search query split into `tag:<name>` terms, which a row must all carry,
+/// and free text matched against the preview according to `FilterCase`.
+struct RowFilter {
+    text: String,
+    case_sensitive: bool,
+    tags: Vec<String>,
+}
+
+impl RowFilter {
+    fn parse(query: &str, case: FilterCase) -> Self {
+        let is_tag_term = |term: &&str| term.starts_with("tag:");
+        let (text, tags) = if query.split_whitespace().any(|term| is_tag_term(&term)) {
+            let tags = query
+                .split_whitespace()
+                .filter_map(|term| term.strip_prefix("tag:"))
+                .filter_map(normalize_tag)
+                .collect();
+            let text = query
+                .split_whitespace()
+                .filter(|term| !is_tag_term(term))
+                .collect::<Vec<_>>()
+                .join(" ");
+            (text, tags)
+        } else {
+            (query.to_string(), Vec::new())
+        };
+        let case_sensitive = match case {
+            FilterCase::Smart => text.chars().any(char::is_uppercase),
+            FilterCase::Sensitive => true,
+            FilterCase::Insensitive => false,
+        };
+        let text = if case_sensitive {
+            text
+        } else {
+            text.to_lowercase()
+        };
+        Self {
+            text,
+            case_sensitive,
+            tags,
+        }
+    }
+
+    fn matches(&self, row: &Row) -> bool {
+        if !self.tags.iter().all(|tag| row.tags.contains(tag)) {
+            return false;
+        }
+        if self.case_sensitive {
+            row.preview.contains(&self.text)
+        } else {
+            row.preview.to_lowercase().contains(&self.text)
+        }
+    }
+}
+
 fn rows_from_items(items: Vec<ConversationItem>) -> Vec<Row> {
     items.into_iter().map(|item| head_to_row(&item)).collect()
 }
@@ -572,18 +880,95 @@ fn head_to_row(item: &ConversationItem) -> Row {
         ts = Some(parsed.with_timezone(&Utc));
     }
 
+    let filename_ts = timestamp_from_rollout_filename(&item.path);
+    if ts.is_none() {
+        ts = filename_ts.and_then(|local| {
+            local
+                .and_local_timezone(chrono::Local)
+                .single()
+                .map(|dt| dt.with_timezone(&Utc))
+        });
+    }
+
     let preview = preview_from_head(&item.head)
         .map(|s| s.trim().to_string())
         .filter(|s| !s.is_empty())
+        .or_else(|| {
+            filename_ts.map(|local| format!("Session from {}", local.format("%Y-%m-%d %H:%M")))
+        })
         .unwrap_or_else(|| String::from("(no message yet)"));
 
+    let cwd = item
+        .head
+        .first()
+        .and_then(|meta| meta.get("cwd"))
+        .and_then(|cwd| cwd.as_str())
+        .filter(|cwd| !cwd.is_empty())
+        .map(PathBuf::from);
+    let cli_version = item
+        .head
+        .first()
+        .and_then(|meta| meta.get("cli_version"))
+        .and_then(|version| version.as_str())
+        .map(str::to_string);
+
     Row {
         path: item.path.clone(),
         preview,
         ts,
+        tags: Vec::new(),
+        cwd,
+        cli_version,
+        active: false,
     }
 }
 
+/// Why resuming `row` deserves a second Enter, if it does.
+fn resume_warning(row: &Row) -> Option<String> {
+    if row.active {
+        return Some("This session was updated moments ago and may still be running.".to_string());
+    }
+    version_mismatch(row.cli_version.as_deref(), CODEX_CLI_VERSION)
+        .map(|recorded| format!("Recorded by Codex v{recorded}, this is v{CODEX_CLI_VERSION}."))
+}
+
+/// Whether a rollout modified at `modified` is likely still being written by
+/// a running session. Modification times in the future count as recent.
+fn is_recently_written(modified: SystemTime, now: SystemTime) -> bool {
+    !now.duration_since(modified)
+        .is_ok_and(|age| age >= ACTIVE_WRITE_WINDOW)
+}
+
+/// The recorded version when a session was written by a Codex version other
+/// than `current`. Sessions without a recorded version are not flagged.
+fn version_mismatch<'a>(recorded: Option<&'a str>, current: &str) -> Option<&'a str> {
+    recorded.filter(|recorded| !recorded.is_empty() && *recorded != current)
+}
+
+/// Local start time embedded in a `rollout-YYYY-MM-DDThh-mm-ss-<uuid>.jsonl`
+/// filename.
+fn timestamp_from_rollout_filename(path: &Path) -> Option<NaiveDateTime> {
+    let name = path.file_name()?.to_str()?;
+    let ts = name
+        .strip_prefix("rollout-")?
+        .get(.."YYYY-MM-DDThh-mm-ss".len())?;
+    NaiveDateTime::parse_from_str(ts, "%Y-%m-%dT%H-%M-%S").ok()
+}
+
+/// Command that opens `dir` in the platform's file manager.
+fn open_dir_command(dir: &Path) -> Command {
+    let program = if cfg!(target_os = "macos") {
+        "open"
+    } else if cfg!(windows) {
+        "explorer"
+    } else {
+        "xdg-open"
+    };
+    let mut command = Command::new(program);
+    command.arg(dir);
+    command
+}
+
 fn preview_from_head(head: &[serde_json::Value]) -> Option<String> {
     head.iter()
         .filter_map(|value| serde_json::from_value::<ResponseItem>(value.clone()).ok())
@@ -642,8 +1027,12 @@ fn draw_picker(tui: &mut Tui, state: &PickerState) -> std::io::Result<()> {
         );
 
         // Search line
-        let q = if state.query.is_empty() {
-            "Type to search".dim().to_string()
+        let q = if let Some(input) = &state.tag_input {
+            format!("Tag: {input}")
+        } else if state.query.is_empty() {
+            "Type to search (tag:<name> filters by tag)"
+                .dim()
+                .to_string()
         } else {
             format!("Search: {}", state.query)
         };
@@ -653,22 +1042,67 @@ fn draw_picker(tui: &mut Tui, state: &PickerState) -> std::io::Result<()> {
         render_list(frame, list, state);
 
         // Hint line
-        let hint_line: Line = vec![
-            "Enter".bold(),
-            " to resume ".into(),
+        let hint_line: Line = if let Some(status) = &state.status {
+            status.clone().red().into()
+        } else if state.tag_input.is_some() {
+            vec![
+                "Enter".bold(),
+                " to add/remove tag ".into(),
+                "• ".dim(),
+                "Esc".bold(),
+                " to cancel".into(),
+            ]
+            .into()
+        } else {
+            key_hints(state).into()
+        };
+        frame.render_widget_ref(hint_line, hint);
+    })
+}
+
+/// Key hints for the browsing state, kept within 80 columns: the less common
+/// actions sit behind Tab so undo and browsing stay visible.
+fn key_hints(state: &PickerState) -> Vec<Span<'static>> {
+    if state.show_more_keys {
+        return vec![
+            "Ctrl+F".into(),
+            " fork ".into(),
             "• ".dim(),
-            "Esc".bold(),
-            " to start new ".into(),
+            "Ctrl+T".into(),
+            " tag ".into(),
             "• ".dim(),
-            "Ctrl+C".into(),
-            " to quit ".into(),
+            "Ctrl+D".into(),
+            " delete ".into(),
             "• ".dim(),
-            "↑/↓".into(),
-            " to browse".dim(),
-        ]
-        .into();
-        frame.render_widget_ref(hint_line, hint);
-    })
+            "Ctrl+O".into(),
+            " open folder ".into(),
+            "• ".dim(),
+            "Tab".bold(),
+            " back".dim(),
+        ];
+    }
+    let mut spans: Vec<Span<'static>> = vec![
+        "Enter".bold(),
+        " resume ".into(),
+        "• ".dim(),
+        "Esc".bold(),
+        " new ".into(),
+        "• ".dim(),
+        "Ctrl+C".into(),
+        " quit ".into(),
+        "• ".dim(),
+    ];
+    if state.last_deleted.is_some() {
+        spans.extend(["Ctrl+U".into(), " undo ".into(), "• ".dim()]);
+    }
+    spans.extend([
+        "Tab".bold(),
+        " more ".into(),
+        "• ".dim(),
+        "↑/↓".into(),
+        " browse".dim(),
+    ]);
+    spans
 }
 
 fn render_list(frame: &mut crate::custom_terminal::Frame, area: Rect, state: &PickerState) {
@@ -696,10 +1130,21 @@ fn render_list(frame: &mut crate::custom_terminal::Frame, area: Rect, state: &Pi
             .map(human_time_ago)
             .unwrap_or_else(|| "".to_string())
             .dim();
+        let active = if row.active { " (active)" } else { "" };
+        let tags: String = row.tags.iter().map(|tag| format!(" #{tag}")).collect();
         let max_cols = area.width.saturating_sub(6) as usize;
-        let preview = truncate_text(&row.preview, max_cols);
-
-        let line: Line = vec![marker, ts, "  ".into(), preview.into()].into();
+        let suffix_cols = active.chars().count() + tags.chars().count();
+        let preview = truncate_text(&row.preview, max_cols.saturating_sub(suffix_cols));
+
+        let line: Line = vec![
+            marker,
+            ts,
+            "  ".into(),
+            preview.into(),
+            active.cyan(),
+            tags.cyan(),
+        ]
+        .into();
         let rect = Rect::new(area.x, y, area.width, 1);
         frame.render_widget_ref(line, rect);
         y = y.saturating_add(1);
@@ -1041,6 +1486,561 @@ mod tests {
         assert_eq!(state.selected, state.filtered_rows.len().saturating_sub(2));
     }
 
+    #[test]
+    fn ctrl_f_forks_selected_session() {
+        let loader: PageLoader = Arc::new(|_| {});
+        let mut state =
+            PickerState::new(PathBuf::from("/tmp"), FrameRequester::test_dummy(), loader);
+
+        state.reset_pagination();
+        state.ingest_page(page(
+            vec![
+                make_item("/tmp/a.jsonl", "2025-01-02T00:00:00Z", "newer"),
+                make_item("/tmp/b.jsonl", "2025-01-01T00:00:00Z", "older"),
+            ],
+            None,
+            2,
+            false,
+        ));
+        state.selected = 1;
+
+        let selection = block_on_future(async {
+            state
+                .handle_key(KeyEvent::new(KeyCode::Char('f'), KeyModifiers::CONTROL))
+                .await
+                .unwrap()
+        });
+        assert!(matches!(
+            selection,
+            Some(ResumeSelection::Fork(path)) if path == Path::new("/tmp/b.jsonl")
+        ));
+        // Plain `f` still types into the search box.
+        let selection = block_on_future(async {
+            state
+                .handle_key(KeyEvent::new(KeyCode::Char('f'), KeyModifiers::NONE))
+                .await
+                .unwrap()
+        });
+        assert!(selection.is_none());
+        assert_eq!(state.query, "f");
+    }
+
+    #[test]
+    fn ctrl_d_trashes_session_and_ctrl_u_restores_it() {
+        let home = tempfile::TempDir::new().unwrap();
+        let newer = home.path().join("sessions/2025/01/02/rollout-a.jsonl");
+        let older = home.path().join("sessions/2025/01/01/rollout-b.jsonl");
+        for path in [&newer, &older] {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "{}\n").unwrap();
+        }
+        let loader: PageLoader = Arc::new(|_| {});
+        let mut state = PickerState::new(
+            home.path().to_path_buf(),
+            FrameRequester::test_dummy(),
+            loader,
+        );
+
+        state.reset_pagination();
+        state.ingest_page(page(
+            vec![
+                make_item(newer.to_str().unwrap(), "2025-01-02T00:00:00Z", "newer"),
+                make_item(older.to_str().unwrap(), "2025-01-01T00:00:00Z", "older"),
+            ],
+            None,
+            2,
+            false,
+        ));
+        let previews = |state: &PickerState| -> Vec<String> {
+            state
+                .filtered_rows
+                .iter()
+                .map(|row| row.preview.clone())
+                .collect()
+        };
+
+        block_on_future(async {
+            state
+                .handle_key(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL))
+                .await
+                .unwrap();
+        });
+        assert_eq!(previews(&state), vec!["older".to_string()]);
+        assert!(!newer.exists());
+        assert_eq!(
+            home.path()
+                .join("sessions/.trash")
+                .read_dir()
+                .unwrap()
+                .count(),
+            1
+        );
+
+        block_on_future(async {
+            state.handle_key(undo_delete_key()).await.unwrap();
+        });
+        assert_eq!(
+            previews(&state),
+            vec!["newer".to_string(), "older".to_string()]
+        );
+        assert!(newer.exists());
+        assert!(state.last_deleted.is_none());
+    }
+
+    // Ctrl+Z never reaches the picker on Unix (it suspends Codex), so undo
+    // must use a key the event stream passes through.
+    fn undo_delete_key() -> KeyEvent {
+        KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL)
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn undo_delete_key_is_not_the_suspend_key() {
+        assert!(!crate::tui::is_suspend_key(&undo_delete_key()));
+        assert!(crate::tui::is_suspend_key(&KeyEvent::new(
+            KeyCode::Char('z'),
+            KeyModifiers::CONTROL
+        )));
+    }
+
+    #[test]
+    fn key_hints_fit_in_80_columns() {
+        let loader: PageLoader = Arc::new(|_| {});
+        let mut state = PickerState::new(
+            PathBuf::from("/tmp/codex-home"),
+            FrameRequester::test_dummy(),
+            loader,
+        );
+        state.last_deleted = Some(DeletedSession {
+            trashed: TrashedSession {
+                original: PathBuf::from("/tmp/a.jsonl"),
+                trashed: PathBuf::from("/tmp/trash/a.jsonl"),
+            },
+            row: Row {
+                path: PathBuf::from("/tmp/a.jsonl"),
+                preview: "deleted".to_string(),
+                ts: None,
+                tags: Vec::new(),
+                cwd: None,
+                cli_version: None,
+                active: false,
+            },
+            index: 0,
+        });
+        let hints = |state: &PickerState| Line::from(key_hints(state)).to_string();
+
+        let basic = hints(&state);
+        assert!(basic.contains("Ctrl+U undo"), "{basic}");
+        assert!(basic.contains("↑/↓ browse"), "{basic}");
+        assert!(Line::from(key_hints(&state)).width() <= 80, "{basic}");
+
+        block_on_future(async {
+            state
+                .handle_key(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE))
+                .await
+                .unwrap();
+        });
+        let more = hints(&state);
+        assert!(more.contains("Ctrl+D delete"), "{more}");
+        assert!(Line::from(key_hints(&state)).width() <= 80, "{more}");
+    }
+
+    #[test]
+    fn version_mismatch_ignores_matching_and_unknown_versions() {
+        assert_eq!(version_mismatch(Some("0.30.0"), "0.31.0"), Some("0.30.0"));
+        assert_eq!(version_mismatch(Some("0.31.0"), "0.31.0"), None);
+        assert_eq!(version_mismatch(Some(""), "0.31.0"), None);
+        assert_eq!(version_mismatch(None, "0.31.0"), None);
+    }
+
+    #[test]
+    fn resuming_other_version_session_requires_second_enter() {
+        let item_with_version = |path: &str, version: &str| ConversationItem {
+            path: PathBuf::from(path),
+            head: vec![
+                json!({ "timestamp": "2025-01-01T00:00:00Z", "cli_version": version }),
+                json!({
+                    "type": "message",
+                    "role": "user",
+                    "content": [{ "type": "input_text", "text": path }],
+                }),
+            ],
+        };
+        let loader: PageLoader = Arc::new(|_| {});
+        let mut state =
+            PickerState::new(PathBuf::from("/tmp"), FrameRequester::test_dummy(), loader);
+        state.reset_pagination();
+        state.ingest_page(page(
+            vec![
+                item_with_version("/tmp/old.jsonl", "0.0.1-old"),
+                item_with_version("/tmp/current.jsonl", CODEX_CLI_VERSION),
+            ],
+            None,
+            2,
+            false,
+        ));
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+
+        let first = block_on_future(async { state.handle_key(enter).await.unwrap() });
+        assert!(first.is_none());
+        assert!(
+            state
+                .status
+                .as_deref()
+                .is_some_and(|status| status.contains("v0.0.1-old"))
+        );
+        let second = block_on_future(async { state.handle_key(enter).await.unwrap() });
+        assert!(matches!(
+            second,
+            Some(ResumeSelection::Resume(path)) if path == Path::new("/tmp/old.jsonl")
+        ));
+
+        // Moving away cancels the pending confirmation.
+        state.selected = 0;
+        block_on_future(async { state.handle_key(enter).await.unwrap() });
+        block_on_future(async {
+            state
+                .handle_key(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE))
+                .await
+                .unwrap()
+        });
+        assert!(state.pending_resume.is_none());
+
+        // Sessions from this version resume immediately.
+        let selection = block_on_future(async { state.handle_key(enter).await.unwrap() });
+        assert!(matches!(
+            selection,
+            Some(ResumeSelection::Resume(path)) if path == Path::new("/tmp/current.jsonl")
+        ));
+    }
+
+    #[test]
+    fn recently_written_rollouts_are_active() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        assert!(is_recently_written(now - Duration::from_secs(2), now));
+        assert!(is_recently_written(now + Duration::from_secs(2), now));
+        assert!(!is_recently_written(now - ACTIVE_WRITE_WINDOW, now));
+        assert!(!is_recently_written(now - Duration::from_secs(3_600), now));
+    }
+
+    #[test]
+    fn resuming_active_session_requires_second_enter() {
+        let home = tempfile::TempDir::new().unwrap();
+        let path = home.path().join("rollout-live.jsonl");
+        std::fs::write(&path, "{}\n").unwrap();
+        let loader: PageLoader = Arc::new(|_| {});
+        let mut state = PickerState::new(
+            home.path().to_path_buf(),
+            FrameRequester::test_dummy(),
+            loader,
+        );
+        state.reset_pagination();
+        state.ingest_page(page(
+            vec![make_item(
+                path.to_str().unwrap(),
+                "2025-01-01T00:00:00Z",
+                "still running",
+            )],
+            None,
+            1,
+            false,
+        ));
+        assert!(state.filtered_rows[0].active);
+
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        let first = block_on_future(async { state.handle_key(enter).await.unwrap() });
+        assert!(first.is_none());
+        assert!(
+            state
+                .status
+                .as_deref()
+                .is_some_and(|status| status.contains("may still be running"))
+        );
+        let second = block_on_future(async { state.handle_key(enter).await.unwrap() });
+        assert!(matches!(second, Some(ResumeSelection::Resume(p)) if p == path));
+    }
+
+    #[test]
+    fn untitled_session_is_labelled_from_filename_timestamp() {
+        let path = PathBuf::from(
+            "/tmp/sessions/2025/05/07/rollout-2025-05-07T17-24-21-5973b6c0-94b8-487b-a530-2aeb6098ae0e.jsonl",
+        );
+        assert_eq!(
+            timestamp_from_rollout_filename(&path),
+            NaiveDateTime::parse_from_str("2025-05-07 17:24:21", "%Y-%m-%d %H:%M:%S").ok()
+        );
+        assert_eq!(
+            timestamp_from_rollout_filename(Path::new("/tmp/notes.jsonl")),
+            None
+        );
+
+        let row = head_to_row(&ConversationItem {
+            path: path.clone(),
+            head: Vec::new(),
+        });
+        assert_eq!(row.preview, "Session from 2025-05-07 17:24");
+        assert!(row.ts.is_some());
+
+        let row = head_to_row(&ConversationItem {
+            path: PathBuf::from("/tmp/notes.jsonl"),
+            head: Vec::new(),
+        });
+        assert_eq!(row.preview, "(no message yet)");
+    }
+
+    #[test]
+    fn row_cwd_comes_from_session_meta_and_builds_opener() {
+        let item = ConversationItem {
+            path: PathBuf::from("/tmp/a.jsonl"),
+            head: vec![
+                json!({ "timestamp": "2025-01-01T00:00:00Z", "cwd": "/work/project" }),
+                json!({
+                    "type": "message",
+                    "role": "user",
+                    "content": [{ "type": "input_text", "text": "hi" }],
+                }),
+            ],
+        };
+        let row = head_to_row(&item);
+        assert_eq!(row.cwd, Some(PathBuf::from("/work/project")));
+
+        let command = open_dir_command(row.cwd.as_deref().unwrap());
+        let expected_program = if cfg!(target_os = "macos") {
+            "open"
+        } else if cfg!(windows) {
+            "explorer"
+        } else {
+            "xdg-open"
+        };
+        assert_eq!(command.get_program(), expected_program);
+        assert_eq!(
+            command.get_args().collect::<Vec<_>>(),
+            vec![std::ffi::OsStr::new("/work/project")]
+        );
+    }
+
+    #[test]
+    fn ctrl_o_reports_missing_cwd_inline() {
+        let loader: PageLoader = Arc::new(|_| {});
+        let mut state =
+            PickerState::new(PathBuf::from("/tmp"), FrameRequester::test_dummy(), loader);
+        let gone = ConversationItem {
+            path: PathBuf::from("/tmp/b.jsonl"),
+            head: vec![
+                json!({ "timestamp": "2025-01-01T00:00:00Z", "cwd": "/definitely/not/here" }),
+                json!({
+                    "type": "message",
+                    "role": "user",
+                    "content": [{ "type": "input_text", "text": "gone" }],
+                }),
+            ],
+        };
+
+        state.reset_pagination();
+        state.ingest_page(page(
+            vec![
+                make_item("/tmp/a.jsonl", "2025-01-02T00:00:00Z", "no cwd"),
+                gone,
+            ],
+            None,
+            2,
+            false,
+        ));
+        let ctrl_o = KeyEvent::new(KeyCode::Char('o'), KeyModifiers::CONTROL);
+
+        block_on_future(async { state.handle_key(ctrl_o).await.unwrap() });
+        assert_eq!(
+            state.status.as_deref(),
+            Some("This session did not record a working directory")
+        );
+
+        state.selected = 1;
+        block_on_future(async { state.handle_key(ctrl_o).await.unwrap() });
+        assert_eq!(
+            state.status.as_deref(),
+            Some("/definitely/not/here no longer exists")
+        );
+
+        // Any other key dismisses the message.
+        block_on_future(async {
+            state
+                .handle_key(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE))
+                .await
+                .unwrap()
+        });
+        assert_eq!(state.status, None);
+    }
+
+    #[test]
+    fn picker_lists_sessions_from_alternate_home() {
+        let active_home = tempfile::TempDir::new().unwrap();
+        let other_home = tempfile::TempDir::new().unwrap();
+        let dir = other_home.path().join("sessions/2025/01/02");
+        std::fs::create_dir_all(&dir).unwrap();
+        let rollout =
+            dir.join("rollout-2025-01-02T10-00-00-00000000-0000-0000-0000-000000000001.jsonl");
+        let lines = [
+            json!({
+                "timestamp": "2025-01-02T10:00:00Z",
+                "type": "session_meta",
+                "payload": {
+                    "id": "00000000-0000-0000-0000-000000000001",
+                    "timestamp": "2025-01-02T10:00:00Z",
+                    "instructions": null,
+                    "cwd": ".",
+                    "originator": "test_originator",
+                    "cli_version": "test_version"
+                }
+            }),
+            json!({
+                "timestamp": "2025-01-02T10:00:00Z",
+                "type": "response_item",
+                "payload": {
+                    "type": "message",
+                    "role": "user",
+                    "content": [{ "type": "input_text", "text": "work session" }]
+                }
+            }),
+            json!({
+                "timestamp": "2025-01-02T10:00:00Z",
+                "type": "event_msg",
+                "payload": { "type": "user_message", "message": "work session", "kind": "plain" }
+            }),
+        ];
+        let contents: String = lines.iter().map(|line| format!("{line}\n")).collect();
+        std::fs::write(&rollout, contents).unwrap();
+
+        let loader: PageLoader = Arc::new(|_| {});
+        let mut active = PickerState::new(
+            active_home.path().to_path_buf(),
+            FrameRequester::test_dummy(),
+            loader.clone(),
+        );
+        block_on_future(active.load_initial_page()).unwrap();
+        assert!(active.filtered_rows.is_empty());
+
+        let mut other = PickerState::new(
+            other_home.path().to_path_buf(),
+            FrameRequester::test_dummy(),
+            loader,
+        );
+        block_on_future(other.load_initial_page()).unwrap();
+        let rows: Vec<_> = other
+            .filtered_rows
+            .iter()
+            .map(|row| (row.path.clone(), row.preview.clone()))
+            .collect();
+        assert_eq!(rows, vec![(rollout, "work session".to_string())]);
+    }
+
+    #[test]
+    fn tagging_selected_row_enables_tag_filter() {
+        let home = tempfile::TempDir::new().unwrap();
+        let loader: PageLoader = Arc::new(|_| {});
+        let mut state = PickerState::new(
+            home.path().to_path_buf(),
+            FrameRequester::test_dummy(),
+            loader,
+        );
+
+        state.reset_pagination();
+        state.ingest_page(page(
+            vec![
+                make_item("/tmp/a.jsonl", "2025-01-02T00:00:00Z", "fix login"),
+                make_item("/tmp/b.jsonl", "2025-01-01T00:00:00Z", "fix parser"),
+            ],
+            None,
+            2,
+            false,
+        ));
+
+        state.selected = 1;
+        block_on_future(async {
+            state
+                .handle_key(KeyEvent::new(KeyCode::Char('t'), KeyModifiers::CONTROL))
+                .await
+                .unwrap();
+            for c in "bugfix".chars() {
+                state
+                    .handle_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+                    .await
+                    .unwrap();
+            }
+            state
+                .handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+                .await
+                .unwrap();
+        });
+        assert!(state.tag_input.is_none());
+        // Typing while tagging must not have leaked into the search query.
+        assert_eq!(state.query, "");
+
+        state.set_query("tag:bugfix".to_string());
+        let previews: Vec<_> = state
+            .filtered_rows
+            .iter()
+            .map(|row| row.preview.as_str())
+            .collect();
+        assert_eq!(previews, vec!["fix parser"]);
+
+        state.set_query("tag:bugfix login".to_string());
+        assert!(state.filtered_rows.is_empty());
+
+        let reloaded = SessionTags::load(home.path());
+        assert_eq!(
+            reloaded.get(Path::new("/tmp/b.jsonl")),
+            vec!["bugfix".to_string()]
+        );
+    }
+
+    #[test]
+    fn filter_uses_smart_case_unless_configured() {
+        let home = tempfile::TempDir::new().unwrap();
+        let loader: PageLoader = Arc::new(|_| {});
+        let mut state = PickerState::new(
+            home.path().to_path_buf(),
+            FrameRequester::test_dummy(),
+            loader,
+        );
+
+        state.reset_pagination();
+        state.ingest_page(page(
+            vec![
+                make_item("/tmp/a.jsonl", "2025-01-02T00:00:00Z", "Fix Login flow"),
+                make_item("/tmp/b.jsonl", "2025-01-01T00:00:00Z", "fix login tests"),
+            ],
+            None,
+            2,
+            false,
+        ));
+        let previews = |state: &PickerState| -> Vec<String> {
+            state
+                .filtered_rows
+                .iter()
+                .map(|row| row.preview.clone())
+                .collect()
+        };
+
+        // Lowercase queries ignore case.
+        state.set_query("login".to_string());
+        assert_eq!(
+            previews(&state),
+            vec!["Fix Login flow".to_string(), "fix login tests".to_string()]
+        );
+
+        // An uppercase letter makes the query case-sensitive.
+        state.set_query("Login".to_string());
+        assert_eq!(previews(&state), vec!["Fix Login flow".to_string()]);
+
+        state.filter_case = FilterCase::Insensitive;
+        state.apply_filter();
+        assert_eq!(previews(&state).len(), 2);
+
+        state.filter_case = FilterCase::Sensitive;
+        state.set_query("fix".to_string());
+        assert_eq!(previews(&state), vec!["fix login tests".to_string()]);
+    }
+
     #[test]
     fn set_query_loads_until_match_and_respects_scan_cap() {
         let recorded_requests: Arc<Mutex<Vec<PageLoadRequest>>> = Arc::new(Mutex::new(Vec::new()));
@@ -0,0 +1,132 @@
+//! Freeform labels that users attach to recorded sessions from the resume
+//! picker. Tags are persisted as a `path -> [tags]` map in
+//! `~/.codex/sessions/tags.json`.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_core::SESSIONS_SUBDIR;
+
+const TAGS_FILE: &str = "tags.json";
+
+#[derive(Debug)]
+pub(crate) struct SessionTags {
+    file: PathBuf,
+    tags: BTreeMap<PathBuf, BTreeSet<String>>,
+}
+
+impl SessionTags {
+    /// Load the tag map stored under `codex_home`. A missing or unreadable
+    /// file yields an empty map so tagging never blocks the picker.
+    pub(crate) fn load(codex_home: &Path) -> Self {
+        let file = codex_home.join(SESSIONS_SUBDIR).join(TAGS_FILE);
+        let tags = match std::fs::read_to_string(&file) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|err| {
+                tracing::warn!("failed to parse {}: {err}", file.display());
+                BTreeMap::new()
+            }),
+            Err(err) => {
+                if err.kind() != io::ErrorKind::NotFound {
+                    tracing::warn!("failed to read {}: {err}", file.display());
+                }
+                BTreeMap::new()
+            }
+        };
+        Self { file, tags }
+    }
+
+    /// Tags for the session at `path`, sorted alphabetically.
+    pub(crate) fn get(&self, path: &Path) -> Vec<String> {
+        self.tags
+            .get(path)
+            .map(|tags| tags.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Add `tag` to the session at `path`, or remove it if already present,
+    /// and persist the result. Returns the session's updated tags.
+    pub(crate) fn toggle(&mut self, path: &Path, tag: &str) -> io::Result<Vec<String>> {
+        let Some(tag) = normalize_tag(tag) else {
+            return Ok(self.get(path));
+        };
+        let entry = self.tags.entry(path.to_path_buf()).or_default();
+        if !entry.remove(&tag) {
+            entry.insert(tag);
+        }
+        if entry.is_empty() {
+            self.tags.remove(path);
+        }
+        self.save()?;
+        Ok(self.get(path))
+    }
+
+    fn save(&self) -> io::Result<()> {
+        if let Some(parent) = self.file.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let contents = serde_json::to_string_pretty(&self.tags).map_err(io::Error::other)?;
+        std::fs::write(&self.file, contents)
+    }
+}
+
+/// Tags are single lowercase words; a leading `#` is accepted and dropped.
+pub(crate) fn normalize_tag(tag: &str) -> Option<String> {
+    let tag = tag.trim().trim_start_matches('#');
+    if tag.is_empty() || tag.contains(char::is_whitespace) {
+        return None;
+    }
+    Some(tag.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    #[test]
+    fn toggle_persists_and_reloads() {
+        let home = TempDir::new().unwrap();
+        let session = home.path().join("sessions/rollout-a.jsonl");
+
+        let mut tags = SessionTags::load(home.path());
+        assert_eq!(tags.get(&session), Vec::<String>::new());
+        tags.toggle(&session, "spike").unwrap();
+        assert_eq!(
+            tags.toggle(&session, "#BugFix").unwrap(),
+            vec!["bugfix".to_string(), "spike".to_string()]
+        );
+
+        let reloaded = SessionTags::load(home.path());
+        assert_eq!(
+            reloaded.get(&session),
+            vec!["bugfix".to_string(), "spike".to_string()]
+        );
+    }
+
+    #[test]
+    fn toggle_removes_existing_tag() {
+        let home = TempDir::new().unwrap();
+        let session = home.path().join("sessions/rollout-a.jsonl");
+
+        let mut tags = SessionTags::load(home.path());
+        tags.toggle(&session, "spike").unwrap();
+        assert_eq!(
+            tags.toggle(&session, "spike").unwrap(),
+            Vec::<String>::new()
+        );
+
+        let reloaded = SessionTags::load(home.path());
+        assert_eq!(reloaded.get(&session), Vec::<String>::new());
+    }
+
+    #[test]
+    fn normalize_tag_rejects_blank_and_multiword() {
+        assert_eq!(normalize_tag("  "), None);
+        assert_eq!(normalize_tag("two words"), None);
+        assert_eq!(normalize_tag(" #Spike "), Some("spike".to_string()));
+    }
+}
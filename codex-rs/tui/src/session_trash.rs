@@ -0,0 +1,172 @@
+//! Soft deletion for recorded sessions. Deleting a session from the resume
+//! picker moves its rollout into `~/.codex/sessions/.trash/` (prefixed with
+//! the deletion time) so it can be restored, and expired entries are purged
+//! when the picker opens.
+
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use codex_core::SESSIONS_SUBDIR;
+use codex_core::SESSIONS_TRASH_SUBDIR;
+
+/// A rollout that was moved into the trash, remembered so it can be restored.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TrashedSession {
+    pub(crate) original: PathBuf,
+    pub(crate) trashed: PathBuf,
+}
+
+fn trash_dir(codex_home: &Path) -> PathBuf {
+    codex_home.join(SESSIONS_SUBDIR).join(SESSIONS_TRASH_SUBDIR)
+}
+
+/// Move the rollout at `path` into the trash under `codex_home`.
+pub(crate) fn move_to_trash(
+    codex_home: &Path,
+    path: &Path,
+    now: SystemTime,
+) -> io::Result<TrashedSession> {
+    let file_name = path.file_name().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("not a session file: {}", path.display()),
+        )
+    })?;
+    let deleted_at = now
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    let dir = trash_dir(codex_home);
+    std::fs::create_dir_all(&dir)?;
+    let trashed = dir.join(format!("{deleted_at}-{}", file_name.to_string_lossy()));
+    std::fs::rename(path, &trashed)?;
+    Ok(TrashedSession {
+        original: path.to_path_buf(),
+        trashed,
+    })
+}
+
+/// Move a trashed rollout back to where it was deleted from. Refuses to
+/// overwrite a file that has since appeared at the original location.
+pub(crate) fn restore(session: &TrashedSession) -> io::Result<()> {
+    if session.original.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", session.original.display()),
+        ));
+    }
+    if let Some(parent) = session.original.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::rename(&session.trashed, &session.original)
+}
+
+/// Permanently remove trashed rollouts deleted more than `retention` before
+/// `now`. Entries whose deletion time cannot be parsed are left alone.
+/// Returns the number of files removed.
+pub(crate) fn purge_expired(
+    codex_home: &Path,
+    retention: Duration,
+    now: SystemTime,
+) -> io::Result<usize> {
+    let entries = match std::fs::read_dir(trash_dir(codex_home)) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(0),
+        Err(err) => return Err(err),
+    };
+    let mut removed = 0;
+    for entry in entries {
+        let entry = entry?;
+        let name = entry.file_name();
+        let Some(deleted_at) = name
+            .to_str()
+            .and_then(|name| name.split_once('-'))
+            .and_then(|(secs, _)| secs.parse::<u64>().ok())
+        else {
+            continue;
+        };
+        let deleted_at = UNIX_EPOCH + Duration::from_secs(deleted_at);
+        let expired = now
+            .duration_since(deleted_at)
+            .is_ok_and(|age| age > retention);
+        if expired {
+            std::fs::remove_file(entry.path())?;
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+    fn write_rollout(home: &Path) -> PathBuf {
+        let dir = home.join("sessions/2025/01/02");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("rollout-2025-01-02T10-00-00-abc.jsonl");
+        std::fs::write(&path, "{}\n").unwrap();
+        path
+    }
+
+    #[test]
+    fn move_to_trash_and_restore_round_trips() {
+        let home = TempDir::new().unwrap();
+        let path = write_rollout(home.path());
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+
+        let trashed = move_to_trash(home.path(), &path, now).unwrap();
+        assert!(!path.exists());
+        assert_eq!(
+            trashed.trashed,
+            home.path()
+                .join("sessions/.trash/1700000000-rollout-2025-01-02T10-00-00-abc.jsonl")
+        );
+        assert!(trashed.trashed.exists());
+
+        restore(&trashed).unwrap();
+        assert!(path.exists());
+        assert!(!trashed.trashed.exists());
+    }
+
+    #[test]
+    fn restore_does_not_overwrite_existing_file() {
+        let home = TempDir::new().unwrap();
+        let path = write_rollout(home.path());
+        let trashed = move_to_trash(home.path(), &path, SystemTime::now()).unwrap();
+        std::fs::write(&path, "new\n").unwrap();
+
+        let err = restore(&trashed).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert!(trashed.trashed.exists());
+    }
+
+    #[test]
+    fn purge_removes_only_expired_entries() {
+        let home = TempDir::new().unwrap();
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let old = move_to_trash(home.path(), &write_rollout(home.path()), now - DAY * 31).unwrap();
+        let recent = move_to_trash(home.path(), &write_rollout(home.path()), now - DAY).unwrap();
+
+        assert_eq!(purge_expired(home.path(), DAY * 30, now).unwrap(), 1);
+        assert!(!old.trashed.exists());
+        assert!(recent.trashed.exists());
+    }
+
+    #[test]
+    fn purge_without_trash_dir_is_a_no_op() {
+        let home = TempDir::new().unwrap();
+        assert_eq!(
+            purge_expired(home.path(), DAY, SystemTime::now()).unwrap(),
+            0
+        );
+    }
+}
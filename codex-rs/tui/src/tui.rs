@@ -188,6 +188,20 @@ fn take_resume_action(pending: &AtomicU8) -> ResumeAction {
     }
 }
 
+/// Ctrl+Z suspends Codex, so views never receive it as a key event.
+#[cfg(unix)]
+pub(crate) fn is_suspend_key(key_event: &KeyEvent) -> bool {
+    matches!(
+        key_event,
+        KeyEvent {
+            code: crossterm::event::KeyCode::Char('z'),
+            modifiers: crossterm::event::KeyModifiers::CONTROL,
+            kind: crossterm::event::KeyEventKind::Press,
+            ..
+        }
+    )
+}
+
 #[derive(Clone, Debug)]
 pub struct FrameRequester {
     frame_schedule_tx: tokio::sync::mpsc::UnboundedSender<Instant>,
@@ -316,16 +330,7 @@ impl Tui {
                         match event {
                             crossterm::event::Event::Key(key_event) => {
                                 #[cfg(unix)]
-                                if matches!(
-                                    key_event,
-                                    crossterm::event::KeyEvent {
-                                        code: crossterm::event::KeyCode::Char('z'),
-                                        modifiers: crossterm::event::KeyModifiers::CONTROL,
-                                        kind: crossterm::event::KeyEventKind::Press,
-                                        ..
-                                    }
-                                )
-                                {
+                                if is_suspend_key(&key_event) {
                                     if alt_screen_active.load(Ordering::Relaxed) {
                                         // Disable alternate scroll when suspending from alt-screen
                                         let _ = execute!(stdout(), DisableAlternateScroll);